@@ -40,7 +40,7 @@ function M.format_diagnostic(filepath, line, column, code, message)
 
   -- Build command with LSP mode flags
   local cmd = string.format(
-    "%s --from-lsp --file %s --line %d --column %d --code %s --message %s 2>&1",
+    "%s --from-lsp --color never --file %s --line %d --column %d --code %s --message %s 2>&1",
     bin,
     vim.fn.shellescape(filepath),
     line,
@@ -58,9 +58,6 @@ function M.format_diagnostic(filepath, line, column, code, message)
   local result = handle:read("*a")
   handle:close()
 
-  -- Strip ANSI color codes for display in diagnostics
-  result = result:gsub("\27%[[%d;]*m", "")
-
   return result ~= "" and result or nil
 end
 
@@ -0,0 +1,92 @@
+use crate::error::TsError;
+
+/// Marker tsc prints with `--build --verbose` before it builds a project
+const BUILDING_PROJECT: &str = "Building project '";
+
+/// Diagnostics reported while building a single project of a `tsc --build` run
+#[derive(Debug, Clone)]
+pub struct ProjectDiagnostics {
+    /// Path of the project's tsconfig.json as printed by tsc. `None` for diagnostics reported
+    /// before any project was built, e.g. errors in the root config.
+    pub project: Option<String>,
+    pub errors:  Vec<TsError>,
+}
+
+/// Parse the output of `tsc --build --verbose --pretty false`.
+///
+/// Every diagnostic is attributed to the project tsc announced it was building last. Projects
+/// that were built without diagnostics are kept with an empty error list, projects that were up
+/// to date and skipped do not show up at all.
+pub fn parse_output(buf: &str) -> Vec<ProjectDiagnostics> {
+    let mut projects = Vec::new();
+    let mut project = None;
+    let mut section = String::new();
+
+    for line in buf.lines() {
+        if let Some(name) = building_project(line) {
+            push_section(&mut projects, project.take(), &section);
+            section.clear();
+            project = Some(name);
+            continue;
+        }
+
+        section.push_str(line);
+        section.push('\n');
+    }
+    push_section(&mut projects, project, &section);
+
+    projects
+}
+
+fn push_section(projects: &mut Vec<ProjectDiagnostics>, project: Option<String>, section: &str) {
+    let errors = crate::parse_output(section);
+
+    // Status output before the first project is only interesting if it contains diagnostics
+    if project.is_some() || !errors.is_empty() {
+        projects.push(ProjectDiagnostics { project, errors });
+    }
+}
+
+/// Extract the project path from a "Building project '...'" status line
+fn building_project(line: &str) -> Option<String> {
+    let (_, rest) = line.split_once(BUILDING_PROJECT)?;
+    let (project, _) = rest.rsplit_once('\'')?;
+    Some(project.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_build_output_attributes_projects() {
+        let buf = "\
+12:00:00 AM - Projects in this build: 
+    * packages/core/tsconfig.json
+    * packages/app/tsconfig.json
+
+12:00:00 AM - Project 'packages/core/tsconfig.json' is out of date because output file 'packages/core/dist/index.js' does not exist
+
+12:00:00 AM - Building project '/repo/packages/core/tsconfig.json'...
+
+packages/core/src/index.ts(1,7): error TS2322: Type 'string' is not assignable to type 'number'.
+12:00:01 AM - Building project '/repo/packages/app/tsconfig.json'...
+
+";
+
+        let projects = parse_output(buf);
+
+        assert_eq!(projects.len(), 2);
+        assert_eq!(
+            projects[0].project.as_deref(),
+            Some("/repo/packages/core/tsconfig.json")
+        );
+        assert_eq!(projects[0].errors.len(), 1);
+        assert_eq!(projects[0].errors[0].file, "packages/core/src/index.ts");
+        assert_eq!(
+            projects[1].project.as_deref(),
+            Some("/repo/packages/app/tsconfig.json")
+        );
+        assert!(projects[1].errors.is_empty());
+    }
+}
@@ -0,0 +1,114 @@
+use std::ops::Range;
+
+use crate::{
+    error::{
+        codes::ErrorCode,
+        core::TsError,
+    },
+    message_parser::{
+        extract_first_quoted,
+        extract_second_quoted,
+    },
+};
+
+/// A concrete edit that resolves an error, replacing a byte range of the source
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fix {
+    pub span:        Range<usize>,
+    pub replacement: String,
+}
+
+/// Compute the edits that fix the error, if there is an unambiguous one.
+///
+/// Fixes are only produced when the text at `span` is exactly what the message talks about, so a
+/// stale log or an unexpected span never produces a wrong edit.
+pub fn fixes(err: &TsError, source: &str, span: &Range<usize>) -> Vec<Fix> {
+    let Some(text) = source.get(span.clone()) else {
+        return Vec::new();
+    };
+
+    let fix = match err.code {
+        // "Property 'x' does not exist on type 'T'. Did you mean 'y'?"
+        ErrorCode::TypoPropertyOnType => {
+            rename(text, extract_first_quoted(&err.message), &err.message)
+        }
+        // "Cannot find name 'x'. Did you mean 'y'?"
+        ErrorCode::CannotFindReference => {
+            rename(text, extract_first_quoted(&err.message), &err.message)
+        }
+        // "Module '"m"' has no exported member named 'x'. Did you mean 'y'?"
+        ErrorCode::NoExportedMember(_) => {
+            rename(text, extract_second_quoted(&err.message), &err.message)
+        }
+        ErrorCode::DisallowedTrailingComma => (text == ",").then(String::new),
+        _ => None,
+    };
+
+    fix.map(|replacement| Fix {
+        span: span.clone(),
+        replacement,
+    })
+    .into_iter()
+    .collect()
+}
+
+/// Replacement for a misspelled name when `text` is the name the message complains about
+fn rename(text: &str, name: Option<String>, message: &str) -> Option<String> {
+    if name.as_deref() != Some(text) {
+        return None;
+    }
+    did_you_mean(message)
+}
+
+/// The name from a trailing "Did you mean 'y'?" of a message
+fn did_you_mean(message: &str) -> Option<String> {
+    let (_, rest) = message.split_once("Did you mean '")?;
+    let (name, _) = rest.split_once('\'')?;
+    Some(name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::parse;
+
+    #[test]
+    fn test_fix_typo_property() {
+        let err = parse(
+            "a.ts(1,9): error TS2551: Property 'lenght' does not exist on type 'string'. Did you \
+             mean 'length'?",
+        )
+        .unwrap();
+        let source = "''.foo; s.lenght;";
+
+        assert_eq!(
+            fixes(&err, source, &(10..16)),
+            vec![Fix {
+                span:        10..16,
+                replacement: "length".to_string(),
+            }]
+        );
+        // The span does not point at the misspelled name
+        assert!(fixes(&err, source, &(3..6)).is_empty());
+    }
+
+    #[test]
+    fn test_fix_no_exported_member() {
+        let err = parse(
+            "a.ts(1,10): error TS2724: '\"./mod\"' has no exported member named 'fooo'. Did you \
+             mean 'foo'?",
+        )
+        .unwrap();
+        let source = "import { fooo } from './mod';";
+
+        assert_eq!(fixes(&err, source, &(9..13))[0].replacement, "foo");
+    }
+
+    #[test]
+    fn test_fix_trailing_comma() {
+        let err = parse("a.ts(1,5): error TS1009: Trailing comma not allowed.").unwrap();
+
+        assert_eq!(fixes(&err, "f(a,)", &(3..4))[0].replacement, "");
+        assert!(fixes(&err, "f(a,)", &(2..3)).is_empty());
+    }
+}
@@ -1 +1,208 @@
+pub mod fixes;
+pub mod styled;
 pub mod suggestions;
+
+use std::{
+    collections::HashMap,
+    ops::Range,
+};
+
+use crate::{
+    diagnostics::{
+        fixes::Fix,
+        suggestions::{
+            ErrorDiagnostic,
+            Suggestion,
+        },
+    },
+    error::{
+        RelatedInformation,
+        TsError,
+    },
+    tokenizer::{
+        Token,
+        Tokenizer,
+    },
+};
+
+/// A `TsError` enriched with its source text, resolved span and suggestion
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub error:      TsError,
+    /// Contents of `error.file`, empty when the file could not be read
+    pub source:     String,
+    /// Byte range in `source` the error points at
+    pub span:       Range<usize>,
+    pub suggestion: Option<Suggestion>,
+    /// Concrete edits that fix the error, empty when there is no unambiguous fix
+    pub fixes:      Vec<Fix>,
+    /// Related locations of the error that could be resolved to a span
+    pub related:    Vec<RelatedSpan>,
+    /// Contents of files other than `error.file` that related locations point into
+    pub sources:    HashMap<String, String>,
+}
+
+/// A related location resolved to a byte range in its file
+#[derive(Debug, Clone)]
+pub struct RelatedSpan {
+    pub info: RelatedInformation,
+    pub span: Range<usize>,
+}
+
+impl Diagnostic {
+    /// Read the source file of the error and resolve its span and suggestion
+    pub fn new(error: TsError) -> Self {
+        let source = if error.is_global() {
+            String::new()
+        } else {
+            std::fs::read_to_string(&error.file).unwrap_or_default()
+        };
+        Self::with_source(error, source)
+    }
+
+    /// Resolve span and suggestion against an already loaded source
+    pub fn with_source(error: TsError, source: String) -> Self {
+        if source.is_empty() {
+            // Global diagnostics and unreadable files can still be explained from the message
+            let suggestion = error.code.suggest(&error, &[]);
+
+            let mut diagnostic = Self {
+                error,
+                source,
+                span: 0..0,
+                suggestion,
+                fixes: Vec::new(),
+                related: Vec::new(),
+                sources: HashMap::new(),
+            };
+            diagnostic.resolve_related(&[]);
+            return diagnostic;
+        }
+
+        let tokens = Tokenizer::new(source.clone()).tokenize();
+        let span = resolve_span(&tokens, &source, error.line, error.column);
+        let suggestion = error.code.suggest(&error, &tokens);
+        let fixes = fixes::fixes(&error, &source, &span);
+
+        let mut diagnostic = Self {
+            error,
+            source,
+            span,
+            suggestion,
+            fixes,
+            related: Vec::new(),
+            sources: HashMap::new(),
+        };
+        diagnostic.resolve_related(&tokens);
+        diagnostic
+    }
+
+    /// Resolve the spans of related locations, reading the other files they point into
+    fn resolve_related(&mut self, tokens: &[Token]) {
+        let mut other_tokens: HashMap<String, Vec<Token>> = HashMap::new();
+
+        for info in &self.error.related {
+            let span = if info.file == self.error.file && !self.source.is_empty() {
+                resolve_span(tokens, &self.source, info.line, info.column)
+            } else {
+                if !self.sources.contains_key(&info.file) {
+                    let Ok(source) = std::fs::read_to_string(&info.file) else {
+                        continue;
+                    };
+                    other_tokens
+                        .insert(info.file.clone(), Tokenizer::new(source.clone()).tokenize());
+                    self.sources.insert(info.file.clone(), source);
+                }
+
+                resolve_span(
+                    &other_tokens[&info.file],
+                    &self.sources[&info.file],
+                    info.line,
+                    info.column,
+                )
+            };
+
+            self.related.push(RelatedSpan {
+                info: info.clone(),
+                span,
+            });
+        }
+    }
+
+    /// Contents of a file the error or one of its related locations points into, `None` when it
+    /// could not be read
+    pub fn source_of(&self, file: &str) -> Option<&str> {
+        match self.sources.get(file) {
+            Some(source) => Some(source),
+            None if file == self.error.file && !self.source.is_empty() => Some(&self.source),
+            None => None,
+        }
+    }
+
+    /// 1-indexed line and column where the span of the error ends, `None` without source. The
+    /// column counts UTF-16 code units like the columns tsc reports.
+    pub fn end_position(&self) -> Option<(usize, usize)> {
+        if self.source.is_empty() {
+            return None;
+        }
+
+        let before = &self.source[..self.span.end.min(self.source.len())];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+
+        Some((line, before[line_start..].encode_utf16().count() + 1))
+    }
+
+    /// Text of the line the error is reported on, `None` without source
+    pub fn source_line(&self) -> Option<&str> {
+        self.source.lines().nth(self.error.line.checked_sub(1)?)
+    }
+
+    /// The span the primary label should point at, preferring the one from the suggestion
+    pub fn label_span(&self) -> Range<usize> {
+        self.suggestion
+            .as_ref()
+            .and_then(|s| s.span.clone())
+            .unwrap_or_else(|| self.span.clone())
+    }
+}
+
+/// Find the span of the token at a 1-indexed line and column, falling back to a single character
+fn resolve_span(tokens: &[Token], src: &str, line: usize, column: usize) -> Range<usize> {
+    tokens
+        .iter()
+        .find(|token| {
+            token.line == line
+                && column.saturating_sub(1) >= token.column
+                && column.saturating_sub(1) < token.column + token.raw.chars().count()
+        })
+        .map(|token| token.start..token.end)
+        // If no token matched, calculate span from line/column
+        .unwrap_or_else(|| span_at(src, line, column))
+}
+
+/// Calculate a single character span from a 1-indexed line and column
+fn span_at(src: &str, line: usize, column: usize) -> Range<usize> {
+    let mut byte_offset = 0;
+    let mut current_line = 1;
+    let mut current_column = 0;
+
+    for ch in src.chars() {
+        if current_line == line && current_column == column.saturating_sub(1) {
+            // Found the position, use a small span for the character
+            let char_len = ch.len_utf8();
+            return byte_offset..byte_offset + char_len;
+        }
+
+        if ch == '\n' {
+            current_line += 1;
+            current_column = 0;
+        } else {
+            current_column += 1;
+        }
+
+        byte_offset += ch.len_utf8();
+    }
+
+    byte_offset.max(1) - 1..byte_offset
+}
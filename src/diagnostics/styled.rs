@@ -0,0 +1,167 @@
+use colored::*;
+
+/// What a segment of suggestion text refers to, renderers decide how each is styled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    Plain,
+    /// Code to write or look up, like a keyword or compiler option
+    Code,
+    /// A type from the message
+    Type,
+    /// A name from the checked code
+    Identifier,
+    /// The expected type or value, or a correction
+    Suggested,
+    /// Marker in front of a suggestion, like `[FATAL]`
+    Label,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+    pub style: Style,
+    pub text:  String,
+}
+
+impl Segment {
+    pub fn new(style: Style, text: impl Into<String>) -> Self {
+        Self {
+            style,
+            text: text.into(),
+        }
+    }
+
+    pub fn plain(text: impl Into<String>) -> Self {
+        Self::new(Style::Plain, text)
+    }
+
+    pub fn code(text: impl Into<String>) -> Self {
+        Self::new(Style::Code, text)
+    }
+
+    pub fn type_name(text: impl Into<String>) -> Self {
+        Self::new(Style::Type, text)
+    }
+
+    pub fn identifier(text: impl Into<String>) -> Self {
+        Self::new(Style::Identifier, text)
+    }
+
+    pub fn suggested(text: impl Into<String>) -> Self {
+        Self::new(Style::Suggested, text)
+    }
+
+    pub fn label(text: impl Into<String>) -> Self {
+        Self::new(Style::Label, text)
+    }
+
+    /// Whether the segment is shown as code, quoted with backticks in plain text
+    pub fn is_code(&self) -> bool {
+        !matches!(self.style, Style::Plain | Style::Label)
+    }
+}
+
+/// Suggestion text made of styled segments
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StyledText {
+    pub segments: Vec<Segment>,
+}
+
+impl StyledText {
+    /// Build text from a template. Every `{}` is replaced by the next segment of `args` and
+    /// backtick spans become code segments.
+    pub fn format(template: &str, args: impl IntoIterator<Item = Segment>) -> Self {
+        let mut args = args.into_iter();
+        let mut segments = Vec::new();
+
+        for (idx, part) in template.split('`').enumerate() {
+            if idx % 2 == 1 {
+                segments.push(Segment::code(part));
+                continue;
+            }
+
+            let mut pieces = part.split("{}");
+            if let Some(first) = pieces.next() {
+                segments.push(Segment::plain(first));
+            }
+            for piece in pieces {
+                segments.extend(args.next());
+                segments.push(Segment::plain(piece));
+            }
+        }
+
+        segments.retain(|segment| !segment.text.is_empty());
+        Self { segments }
+    }
+
+    /// Text without styling, code segments are quoted with backticks
+    pub fn plain(&self) -> String {
+        self.segments
+            .iter()
+            .map(|segment| {
+                if segment.is_code() {
+                    format!("`{}`", segment.text)
+                } else {
+                    segment.text.clone()
+                }
+            })
+            .collect()
+    }
+
+    /// Text styled with ANSI escapes for the terminal, unless colors are disabled
+    pub fn ansi(&self) -> String {
+        self.segments
+            .iter()
+            .map(|segment| {
+                let text = segment.text.as_str();
+                let styled = match segment.style {
+                    Style::Plain => return text.to_string(),
+                    Style::Code => text.bold(),
+                    Style::Type => text.yellow().bold(),
+                    Style::Identifier => text.red().bold(),
+                    Style::Suggested => text.green().bold(),
+                    Style::Label => return text.bright_red().bold().italic().to_string(),
+                };
+                format!("`{}`", styled)
+            })
+            .collect()
+    }
+}
+
+impl From<&str> for StyledText {
+    fn from(template: &str) -> Self {
+        Self::format(template, [])
+    }
+}
+
+impl std::fmt::Display for StyledText {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.plain())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let text = StyledText::format(
+            "Try converting {} to {} with `as`.",
+            [Segment::identifier("x"), Segment::suggested("number")],
+        );
+
+        assert_eq!(
+            text.segments,
+            vec![
+                Segment::plain("Try converting "),
+                Segment::identifier("x"),
+                Segment::plain(" to "),
+                Segment::suggested("number"),
+                Segment::plain(" with "),
+                Segment::code("as"),
+                Segment::plain("."),
+            ]
+        );
+        assert_eq!(text.plain(), "Try converting `x` to `number` with `as`.");
+    }
+}
@@ -1,6 +1,8 @@
-use colored::*;
-
 use crate::{
+    diagnostics::styled::{
+        Segment,
+        StyledText,
+    },
     error::{
         codes::ErrorCode,
         core::TsError,
@@ -24,11 +26,30 @@ use crate::{
     tokenizer::Token,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct Suggestion {
-    pub suggestions: Vec<String>,
-    pub help:        Option<String>,
-    pub span:        Option<std::ops::Range<usize>>,
+    /// Message of the primary label, at `span` or the span of the error
+    pub label:     Option<StyledText>,
+    /// Further explanations, shown below the code frame
+    pub notes:     Vec<StyledText>,
+    /// Other places in the file that explain the error, each with its own label
+    pub secondary: Vec<SecondaryLabel>,
+    pub help:      Option<StyledText>,
+    pub span:      Option<std::ops::Range<usize>>,
+}
+
+/// A label on a span other than the one the error points at
+#[derive(Debug, Clone)]
+pub struct SecondaryLabel {
+    pub span:    std::ops::Range<usize>,
+    pub message: StyledText,
+}
+
+impl Suggestion {
+    /// The label message followed by the notes
+    pub fn messages(&self) -> impl Iterator<Item = &StyledText> {
+        self.label.iter().chain(&self.notes)
+    }
 }
 
 /// Trait that implements diagnostics for TS Errors
@@ -43,11 +64,11 @@ impl ErrorDiagnostic for ErrorCode {
             ErrorCode::TypeMismatch => suggest_type_mismatch(err, tokens),
             ErrorCode::InlineTypeMismatch => suggest_inline_type_mismatch(err),
             ErrorCode::MissingParameters => suggest_missing_parameters(err, tokens),
-            ErrorCode::NoImplicitAny => suggest_no_implicit_any(err),
+            ErrorCode::NoImplicitAny(_) => suggest_no_implicit_any(err),
             ErrorCode::PropertyMissingInType => suggest_property_missing_in_type(err, tokens),
             ErrorCode::UnintentionalComparison => suggest_unintentional_comparison(),
             ErrorCode::PropertyDoesNotExist => suggest_property_does_not_exist(err),
-            ErrorCode::ObjectIsPossiblyUndefined => suggest_possibly_undefined(err),
+            ErrorCode::ObjectIsPossiblyUndefined(_) => suggest_possibly_undefined(err),
             ErrorCode::DirectCastPotentiallyMistaken => suggest_direct_cast_mistaken(err),
             ErrorCode::SpreadArgumentMustBeTupleType => suggest_spread_tuple(),
             ErrorCode::RightSideArithmeticMustBeEnumberable => suggest_right_arithmetic(err),
@@ -65,8 +86,8 @@ impl ErrorDiagnostic for ErrorCode {
             ErrorCode::InvalidIndexType => suggest_invalid_index_type(err),
             ErrorCode::InvalidIndexTypeSignature => suggest_invalid_index_signature(err, tokens),
             ErrorCode::TypoPropertyOnType => suggest_typo_property(err),
-            ErrorCode::ObjectIsPossiblyNull => suggest_possibly_null(err),
-            ErrorCode::ObjectIsUnknown => suggest_object_unknown(err),
+            ErrorCode::ObjectIsPossiblyNull(_) => suggest_possibly_null(err),
+            ErrorCode::ObjectIsUnknown(_) => suggest_object_unknown(err),
             ErrorCode::UnterminatedStringLiteral => suggest_unterminated_string(err),
             ErrorCode::IdentifierExpected => suggest_identifier_expected(),
             ErrorCode::DisallowedTrailingComma => suggest_disallowed_comma(),
@@ -77,11 +98,11 @@ impl ErrorDiagnostic for ErrorCode {
             ErrorCode::YieldNotInGenerator => suggest_yield_not_in_generator(),
             ErrorCode::JsxFlagNotProvided => suggest_jsx_flag(),
             ErrorCode::DeclaredButNeverUsed => suggest_declared_unused(err),
-            ErrorCode::NoExportedMember => suggest_no_exported_member(err),
+            ErrorCode::NoExportedMember(_) => suggest_no_exported_member(err),
             ErrorCode::ImportedButNeverUsed => suggest_imported_unused(),
             ErrorCode::InvalidDefaultImport => suggest_invalid_default_import(),
             ErrorCode::UnreachableCode => suggest_unreachable(),
-            ErrorCode::TypeAssertionInJsNotAllowed => suggest_type_assertion_in_js_not_allowed(),
+            ErrorCode::TypeAssertionInJsNotAllowed(_) => suggest_type_assertion_in_js_not_allowed(),
             ErrorCode::MappedTypeMustBeStatic => suggest_mapped_type_must_be_static(),
             ErrorCode::ElementImplicitAnyInvalidIndexTypeForObject => {
                 suggest_element_implicit_any_invalid_index_type_for_object(err)
@@ -102,25 +123,141 @@ impl ErrorDiagnostic for ErrorCode {
             ErrorCode::TypesOfPropertyAreIncompatible => {
                 suggest_types_of_property_are_incompatible(err)
             }
+            ErrorCode::UnknownCompilerOption(_) => suggest_unknown_compiler_option(err),
+            ErrorCode::InvalidCompilerOptionValue(_) => suggest_invalid_compiler_option_value(err),
+            ErrorCode::CannotReadFile(_) => suggest_cannot_read_file(err),
+            ErrorCode::TsconfigNotFound(_) => suggest_tsconfig_not_found(err),
+            ErrorCode::NoInputsFound => suggest_no_inputs_found(err),
             ErrorCode::Unsupported(_) => None,
         }
     }
 }
 
+/// Suggestion for an unknown option in tsconfig.json or on the command line. TS5025 also carries
+/// the option tsc thinks was meant.
+fn suggest_unknown_compiler_option(err: &TsError) -> Option<Suggestion> {
+    let option = extract_first_quoted(&err.message)?;
+
+    let help = match extract_second_quoted(&err.message) {
+        Some(correction) => {
+            StyledText::format("Did you mean {}?", [Segment::suggested(correction)])
+        }
+        None => {
+            "Check the spelling of the option, or whether it requires a newer TypeScript version."
+                .into()
+        }
+    };
+
+    Some(Suggestion {
+        label: Some(StyledText::format(
+            "{} is not a known compiler option.",
+            [Segment::identifier(option)],
+        )),
+        help: Some(help),
+        span: None,
+        ..Default::default()
+    })
+}
+
+/// Suggestion for a compiler option that was given a value of the wrong type or an unsupported
+/// value
+fn suggest_invalid_compiler_option_value(err: &TsError) -> Option<Suggestion> {
+    let option = extract_first_quoted(&err.message)?;
+
+    let help = match err.message.split_once("must be: ") {
+        Some((_, allowed)) => StyledText::format(
+            "Set {} to one of: {}",
+            [
+                Segment::code(&option),
+                Segment::plain(allowed.trim_end_matches('.')),
+            ],
+        ),
+        None => StyledText::format(
+            "Check the value type expected by {} in the TypeScript documentation.",
+            [Segment::code(&option)],
+        ),
+    };
+
+    Some(Suggestion {
+        label: Some(StyledText::format(
+            "Compiler option {} has an invalid value.",
+            [Segment::identifier(option)],
+        )),
+        help: Some(help),
+        span: None,
+        ..Default::default()
+    })
+}
+
+/// Suggestion for a file referenced by the configuration that does not exist or can not be read
+fn suggest_cannot_read_file(err: &TsError) -> Option<Suggestion> {
+    let file = extract_first_quoted(&err.message)?;
+
+    Some(Suggestion {
+        label: Some(StyledText::format(
+            "{} can not be read.",
+            [Segment::identifier(file)],
+        )),
+        help:  Some(
+            "Ensure the path in `extends`, `files` or `references` of tsconfig.json is correct and the file exists."
+                .into(),
+        ),
+        span:  None,
+        ..Default::default()
+    })
+}
+
+/// Suggestion for when the project passed to tsc does not contain a tsconfig.json
+fn suggest_tsconfig_not_found(err: &TsError) -> Option<Suggestion> {
+    let path = extract_first_quoted(&err.message)?;
+
+    Some(Suggestion {
+        label: Some(StyledText::format(
+            "No tsconfig.json found at {}.",
+            [Segment::identifier(path)],
+        )),
+        help: Some(
+            "Pass the path of an existing config with `--project` or run from the project root."
+                .into(),
+        ),
+        span: None,
+        ..Default::default()
+    })
+}
+
+/// Suggestion for when the `include` and `files` of a config match no source files
+fn suggest_no_inputs_found(err: &TsError) -> Option<Suggestion> {
+    let config = extract_first_quoted(&err.message)?;
+
+    Some(Suggestion {
+        label: Some(StyledText::format(
+            "{} does not match any source files.",
+            [Segment::identifier(config)],
+        )),
+        help:  Some(
+            "Ensure the `include` patterns match at least one file and are not all filtered out by `exclude`."
+                .into(),
+        ),
+        span:  None,
+        ..Default::default()
+    })
+}
+
 /// Suggestion for when types of a property are incompatible between source and target
 fn suggest_types_of_property_are_incompatible(err: &TsError) -> Option<Suggestion> {
     let property = extract_first_quoted(&err.message)?;
 
     Some(Suggestion {
-        suggestions: vec![format!(
-            "Types of property `{}` are incompatible between the source and target.",
-            property.red().bold()
-        )],
-        help:        Some(
+        label: Some(StyledText::format(
+            "Types of property {} are incompatible between the source and target.",
+            [Segment::identifier(property)],
+        )),
+        help: Some(
             "Ensure that the property types are compatible or perform necessary type conversions."
-                .to_string(),
+                .into(),
         ),
-        span:        None,
+        span: None,
+        ..Default::default()
     })
 }
 
@@ -130,15 +267,13 @@ fn suggest_jsx_incorrect_configuration_umd(err: &TsError) -> Option<Suggestion>
     let module_name = extract_first_quoted(&err.message)?;
 
     Some(Suggestion {
-        suggestions: vec![
-            format!(
-                "`{}` refers to a UMD global, current file is a module.",
-                module_name.red().bold(),
-            ),
-            format!("Consider using `{}` instead.", "import".yellow().bold()),
-        ],
-        help:        Some("Double check tsconfig.json for jsx configuration.".to_string()),
-        span:        None,
+        label: Some(StyledText::format(
+            "{} refers to a UMD global, current file is a module.",
+            [Segment::identifier(module_name)],
+        )),
+        notes: vec!["Consider using `import` instead.".into()],
+        help: Some("Double check tsconfig.json for jsx configuration.".into()),
+        ..Default::default()
     })
 }
 
@@ -147,32 +282,32 @@ fn suggest_jsx_element_not_a_fn(err: &TsError) -> Option<Suggestion> {
     let jsx_element = extract_first_quoted(&err.message)?;
 
     Some(Suggestion {
-        suggestions: vec![format!(
-            "`{}`` is not a valid function.",
-            jsx_element.red().bold()
-        )],
-        help:        Some(format!(
-            "Ensure that `{}` has a valid and callable signature.",
-            jsx_element.red().bold()
+        label: Some(StyledText::format(
+            "{} is not a valid function.",
+            [Segment::identifier(&jsx_element)],
+        )),
+        help: Some(StyledText::format(
+            "Ensure that {} has a valid and callable signature.",
+            [Segment::identifier(jsx_element)],
         )),
-        span:        None,
+        span: None,
+        ..Default::default()
     })
 }
 
 /// Suggestion for when a union is too complex
 fn suggest_union_too_complex() -> Option<Suggestion> {
     Some(Suggestion {
-        suggestions: vec![
-            format!(
-                "{} The union type exceeds the maximum allowed number of combinations .",
-                "[FATAL]".bright_red().bold().italic(),
-            )
-        ],
-        help:        Some(
+        label: Some(StyledText::format(
+            "{} The union type exceeds the maximum allowed number of combinations .",
+            [Segment::label("[FATAL]")],
+        )),
+        help:  Some(
             "Consider re-evaluating the design. The largest allowed union size is 100_000 combinations"
-                .to_string(),
+                .into(),
         ),
-        span:        None,
+        span:  None,
+        ..Default::default()
     })
 }
 
@@ -188,14 +323,17 @@ fn suggest_invaid_operator_usage(err: &TsError) -> Option<Suggestion> {
     let second_type = extract_third_quoted(&err.message)?;
 
     Some(Suggestion {
-        suggestions: vec![format!(
-            "Operator `{}` is not valid for `{}` and `{}`.",
-            operator.red().bold(),
-            first_type.red().bold(),
-            second_type.red().bold()
-        )],
-        help:        Some("Ensure that the operator is valid for the operand types.".to_string()),
-        span:        None,
+        label: Some(StyledText::format(
+            "Operator {} is not valid for {} and {}.",
+            [
+                Segment::identifier(operator),
+                Segment::type_name(first_type),
+                Segment::type_name(second_type),
+            ],
+        )),
+        help: Some("Ensure that the operator is valid for the operand types.".into()),
+        span: None,
+        ..Default::default()
     })
 }
 
@@ -205,17 +343,32 @@ fn suggest_invaid_operator_usage(err: &TsError) -> Option<Suggestion> {
 fn suggest_duplicate_fn_decl(err: &TsError, tokens: &[Token]) -> Option<Suggestion> {
     let fn_name = extract_identifier_at_error(err, tokens)?;
 
+    // The other declarations of the function, tsc reports each of them separately
+    let secondary = tokens
+        .windows(2)
+        .filter(|pair| pair[0].raw == "function" && pair[1].raw == fn_name)
+        .map(|pair| &pair[1])
+        .filter(|token| token.line != err.line)
+        .map(|token| SecondaryLabel {
+            span:    token.start..token.end,
+            message: "Also declared here.".into(),
+        })
+        .collect();
+
     Some(Suggestion {
-        suggestions: vec![format!(
-            "Function `{}` is declared multiple times in the same scope.",
-            fn_name.red().bold()
-        )],
-        help:        Some(format!(
-            "Consider renaming or removing the duplicate declaration of `{}` on line {}.",
-            fn_name.red().bold(),
-            err.line
+        label: Some(StyledText::format(
+            "Function {} is declared multiple times in the same scope.",
+            [Segment::identifier(&fn_name)],
+        )),
+        secondary,
+        help: Some(StyledText::format(
+            "Consider renaming or removing the duplicate declaration of {} on line {}.",
+            [
+                Segment::identifier(fn_name),
+                Segment::plain(err.line.to_string()),
+            ],
         )),
-        span:        None,
+        ..Default::default()
     })
 }
 
@@ -225,15 +378,16 @@ fn suggest_cannot_find_reference(err: &TsError) -> Option<Suggestion> {
     let suggested_correction = extract_second_quoted(&err.message)?;
 
     Some(Suggestion {
-        suggestions: vec![format!(
-            "`{}` is not in scope or does not exit",
-            unfindable_reference.red().bold()
-        )],
-        help:        Some(format!(
-            "Did you mean to reference `{}`?",
-            suggested_correction.green().bold()
+        label: Some(StyledText::format(
+            "{} is not in scope or does not exit",
+            [Segment::identifier(unfindable_reference)],
+        )),
+        help: Some(StyledText::format(
+            "Did you mean to reference {}?",
+            [Segment::suggested(suggested_correction)],
         )),
-        span:        None,
+        span: None,
+        ..Default::default()
     })
 }
 
@@ -242,15 +396,13 @@ fn suggest_unexpected_kw_or_identifier(err: &TsError, tokens: &[Token]) -> Optio
     let keyword = find_token_at_position(tokens, err.line, err.column)?;
 
     Some(Suggestion {
-        suggestions: vec![format!(
-            "{} `{}` is not expected in this context.",
-            "[FATAL]".bright_red().bold().italic(),
-            keyword.raw.bright_yellow().bold()
-        )],
-        help:        Some(
-            "Avoid using unknown, undeclared or invalid keywords or identifiers.".to_string(),
-        ),
-        span:        None,
+        label: Some(StyledText::format(
+            "{} {} is not expected in this context.",
+            [Segment::label("[FATAL]"), Segment::identifier(&keyword.raw)],
+        )),
+        help: Some("Avoid using unknown, undeclared or invalid keywords or identifiers.".into()),
+        span: None,
+        ..Default::default()
     })
 }
 
@@ -260,26 +412,26 @@ fn suggest_jsx_not_set(err: &TsError) -> Option<Suggestion> {
     let resolved_name = extract_second_quoted(&err.message)?;
 
     Some(Suggestion {
-        suggestions: vec![format!(
-            "Module `{}` is resolved to `{}` but jsx compiler flag is not set.",
-            module_name.red().bold(),
-            resolved_name.red().bold()
-        )],
-        help:        Some("Enable `--jsx` compiler flag or add jsx to tsconfig.json".to_string()),
-        span:        None,
+        label: Some(StyledText::format(
+            "Module {} is resolved to {} but jsx compiler flag is not set.",
+            [
+                Segment::identifier(module_name),
+                Segment::identifier(resolved_name),
+            ],
+        )),
+        help: Some("Enable `--jsx` compiler flag or add jsx to tsconfig.json".into()),
+        span: None,
+        ..Default::default()
     })
 }
 
 /// Suggestion for when isolatedModules is enabled and ambiend const enums are used.
 fn suggest_const_enums_disallowed() -> Option<Suggestion> {
     Some(Suggestion {
-        suggestions: vec![
-            "Disable `isolatedModules` as a compiler setting to allow const enums.".to_string(),
-        ],
-        help:        Some(
-            "Const enums are not valid when `isolatedModules` is enabled.".to_string(),
-        ),
-        span:        None,
+        label: Some("Disable `isolatedModules` as a compiler setting to allow const enums.".into()),
+        help: Some("Const enums are not valid when `isolatedModules` is enabled.".into()),
+        span: None,
+        ..Default::default()
     })
 }
 
@@ -287,78 +439,79 @@ fn suggest_const_enums_disallowed() -> Option<Suggestion> {
 /// explained here <https://www.totaltypescript.com/what-is-jsx-intrinsicelements>
 fn suggest_missing_jsx_intrinsic_elements_declaration() -> Option<Suggestion> {
     Some(Suggestion {
-        suggestions: vec![
-            "JSX intrinsic elements declaration is missing in global scope.".to_string(),
-        ],
-        help:        Some(
+        label: Some("JSX intrinsic elements declaration is missing in global scope.".into()),
+        help:  Some(
             "Either declare a global module with a JSX namespace or configure React or other JSX consumers correctly"
-                .to_string(),
+                .into(),
         ),
-        span:        None,
+        span:  None,
+        ..Default::default()
     })
 }
 
 /// Suggestion for when element is implicitly any and that index type is invalid for indexing
 /// object
 fn suggest_element_implicit_any_invalid_index_type_for_object(err: &TsError) -> Option<Suggestion> {
-    let implicit_type = extract_quoted_value(&err.message, 1)?;
+    let message = err.full_message();
+    let implicit_type = extract_quoted_value(&message, 1)?;
 
-    let index_type = extract_quoted_value(&err.message, 3)?;
-    let object_to_index = extract_quoted_value(&err.message, 6)?;
+    let index_type = extract_quoted_value(&message, 3)?;
+    let object_to_index = extract_quoted_value(&message, 6)?;
 
     Some(Suggestion {
-        suggestions: vec![format!(
-            "`{}` can not be used as an index to access `{}` - therefore element is implicitly `{}`.",
-            index_type.red().bold(),
-            object_to_index.red().bold(),
-            implicit_type.red().bold()
-        )],
-        help:        Some(format!(
-            "Consider declaring the index with `{}` or loosen the type of `{}` to allow indexing with `{}`.",
-            format!(
-                "{} {}",
-                "keyof typeof".yellow().bold(),
-                object_to_index.yellow().bold()
-            ),
-            object_to_index.yellow().bold(),
-            index_type.yellow().bold()
+        label: Some(StyledText::format(
+            "{} can not be used as an index to access {} - therefore element is implicitly {}.",
+            [
+                Segment::type_name(&index_type),
+                Segment::identifier(&object_to_index),
+                Segment::type_name(implicit_type),
+            ],
+        )),
+        help: Some(StyledText::format(
+            "Consider declaring the index with {} or loosen the type of {} to allow indexing with {}.",
+            [
+                Segment::code(format!("keyof typeof {}", object_to_index)),
+                Segment::identifier(&object_to_index),
+                Segment::type_name(index_type),
+            ],
         )),
-        span:        None,
+        span: None,
+        ..Default::default()
     })
 }
 
 /// Suggestion for mapped types with non-static keys
 fn suggest_mapped_type_must_be_static() -> Option<Suggestion> {
     Some(Suggestion {
-        suggestions: vec![
-            "Consider removing the properties and/or methods".to_string(),
-        ],
-        help:        Some(
+        label: Some("Consider removing the properties and/or methods".into()),
+        help:  Some(
             "Split multiple mapped property declarations into individual types and combine them using a type intersection."
-                .to_string(),
+                .into(),
         ),
-        span:        None,
+        span:  None,
+        ..Default::default()
     })
 }
 
 /// Suggestiong for using type assertions and annotations outside of TypeScript files
 fn suggest_type_assertion_in_js_not_allowed() -> Option<Suggestion> {
     Some(Suggestion {
-        suggestions: vec!["Type assertions are not allowed in JavaScript files.".to_string()],
-        help:        Some(
-            "Consider converting the file to TypeScript or removing the type assertion."
-                .to_string(),
+        label: Some("Type assertions are not allowed in JavaScript files.".into()),
+        help: Some(
+            "Consider converting the file to TypeScript or removing the type assertion.".into(),
         ),
-        span:        None,
+        span: None,
+        ..Default::default()
     })
 }
 
 /// Suggestion for TS95050
 fn suggest_unreachable() -> Option<Suggestion> {
     Some(Suggestion {
-        suggestions: vec!["Code here is unreachable".to_string()],
-        help:        Some("Consider removing unreachable code or the statement that causes this to be unreachable".to_string()),
-        span:        None,
+        label: Some("Code here is unreachable".into()),
+        help:  Some("Consider removing unreachable code or the statement that causes this to be unreachable".into()),
+        span:  None,
+        ..Default::default()
     })
 }
 
@@ -367,18 +520,33 @@ fn suggest_type_mismatch(err: &TsError, tokens: &[Token]) -> Option<Suggestion>
     if let Some((from, to)) = parse_ts2322_error(&err.message) {
         let var_name = extract_identifier_or_default(err, tokens, "");
 
+        let label = StyledText::format(
+            "Try converting {} from {} to {}.",
+            [
+                Segment::identifier(var_name),
+                Segment::type_name(&from),
+                Segment::suggested(&to),
+            ],
+        );
+
+        // Point at the most specific elaboration when the mismatch is nested, e.g. in a property
+        let mut notes = Vec::new();
+        if let Some((inner_from, inner_to)) = parse_ts2322_error(err.deepest_message())
+            && (inner_from != from || inner_to != to)
+        {
+            notes.push(StyledText::format(
+                "The mismatch originates where {} is not assignable to {}.",
+                [Segment::type_name(inner_from), Segment::suggested(inner_to)],
+            ));
+        }
+
         Some(Suggestion {
-            suggestions: vec![format!(
-                "Try converting `{}` from `{}` to `{}`.",
-                var_name.yellow().bold().italic(),
-                from.red().bold(),
-                to.green().bold()
-            )],
-            help:        Some(
-                "Ensure that the types are compatible or perform an explicit conversion."
-                    .to_string(),
+            label: Some(label),
+            notes,
+            help: Some(
+                "Ensure that the types are compatible or perform an explicit conversion.".into(),
             ),
-            span:        None,
+            ..Default::default()
         })
     } else {
         None
@@ -386,11 +554,10 @@ fn suggest_type_mismatch(err: &TsError, tokens: &[Token]) -> Option<Suggestion>
 }
 
 fn suggest_inline_type_mismatch(err: &TsError) -> Option<Suggestion> {
-    if err
-        .message
-        .contains("Target signature provides too few arguments")
-    {
-        let (expected, got) = if let Some(expected_str) = err.message.split("Expected ").nth(1) {
+    let message = err.full_message();
+
+    if message.contains("Target signature provides too few arguments") {
+        let (expected, got) = if let Some(expected_str) = message.split("Expected ").nth(1) {
             let expected_num = expected_str
                 .split(" or more")
                 .next()
@@ -408,36 +575,37 @@ fn suggest_inline_type_mismatch(err: &TsError) -> Option<Suggestion> {
         };
 
         let suggestion = if expected > 0 && got > 0 {
-            format!(
-                "The callback function has {} parameters, but the signature only accepts {}.",
-                expected, got
+            StyledText::from(
+                format!(
+                    "The callback function has {} parameters, but the signature only accepts {}.",
+                    expected, got
+                )
+                .as_str(),
             )
         } else {
-            "The callback function has too many parameters for the expected signature.".to_string()
+            "The callback function has too many parameters for the expected signature.".into()
         };
 
         return Some(Suggestion {
-            suggestions: vec![suggestion],
+            label: Some(suggestion),
             help: Some(
-                "Remove the extra parameters from the callback function to match the expected signature.".to_string()
+                "Remove the extra parameters from the callback function to match the expected signature.".into()
             ),
             span: None,
+            ..Default::default()
         });
     }
 
-    if err
-        .message
-        .contains("Target signature provides too many arguments")
-    {
+    if message.contains("Target signature provides too many arguments") {
         return Some(Suggestion {
-            suggestions: vec![
-                "The callback function has too few parameters for the expected signature."
-                    .to_string(),
-            ],
+            label: Some(
+                "The callback function has too few parameters for the expected signature.".into(),
+            ),
             help: Some(
-                "Add the missing parameters to the callback function to match the expected signature.".to_string()
+                "Add the missing parameters to the callback function to match the expected signature.".into()
             ),
             span: None,
+            ..Default::default()
         });
     }
 
@@ -449,11 +617,13 @@ fn suggest_inline_type_mismatch(err: &TsError) -> Option<Suggestion> {
                 mismatches
                     .iter()
                     .map(|(property, provided, expected)| {
-                        format!(
-                            "Property `{}` is provided as `{}` but expects `{}`.",
-                            property.red().bold(),
-                            provided.red().bold(),
-                            expected.green().bold()
+                        StyledText::format(
+                            "Property {} is provided as {} but expects {}.",
+                            [
+                                Segment::identifier(property),
+                                Segment::type_name(provided),
+                                Segment::suggested(expected),
+                            ],
                         )
                     })
                     .collect(),
@@ -463,15 +633,29 @@ fn suggest_inline_type_mismatch(err: &TsError) -> Option<Suggestion> {
         None
     };
 
+    // Without a property level mismatch, the deepest elaboration is the most specific cause
+    let suggestions = suggestions.or_else(|| {
+        let (from, to) = parse_ts2322_error(err.deepest_message())?;
+        Some(vec![StyledText::format(
+            "Type {} is not assignable to {}.",
+            [Segment::type_name(from), Segment::suggested(to)],
+        )])
+    });
+
+    // The first mismatch is the label, any further ones become notes
+    let mut messages = suggestions.unwrap_or_else(|| {
+        vec!["Argument type does not match the expected parameter type.".into()]
+    });
+    let label = messages.remove(0);
+
     Some(Suggestion {
-        suggestions: suggestions.unwrap_or_else(|| {
-            vec!["Argument type does not match the expected parameter type.".to_string()]
-        }),
-        help:        Some(
+        label: Some(label),
+        notes: messages,
+        help: Some(
             "Check the function arguments to ensure they match the expected parameter types."
-                .to_string(),
+                .into(),
         ),
-        span:        None,
+        ..Default::default()
     })
 }
 
@@ -506,53 +690,64 @@ fn suggest_missing_parameters(err: &TsError, tokens: &[Token]) -> Option<Suggest
 
     let (suggestion, help) = match (expected, got) {
         (Some(exp), Some(g)) if g < exp => (
-            format!(
-                "Function `{}` expects {} arguments but only received {}.",
-                fn_name.red().bold(),
-                exp,
-                g
+            StyledText::format(
+                "Function {} expects {} arguments but only received {}.",
+                [
+                    Segment::identifier(fn_name),
+                    Segment::plain(exp.to_string()),
+                    Segment::plain(g.to_string()),
+                ],
             ),
-            format!(
-                "Add the missing {} to match the expected signature.",
-                if exp - g == 1 {
-                    "argument"
-                } else {
-                    "arguments"
-                }
+            StyledText::from(
+                format!(
+                    "Add the missing {} to match the expected signature.",
+                    if exp - g == 1 {
+                        "argument"
+                    } else {
+                        "arguments"
+                    }
+                )
+                .as_str(),
             ),
         ),
         (Some(exp), Some(g)) if g > exp => (
-            format!(
-                "Function `{}` expects {} arguments but received {}.",
-                fn_name.red().bold(),
-                exp,
-                g
+            StyledText::format(
+                "Function {} expects {} arguments but received {}.",
+                [
+                    Segment::identifier(fn_name),
+                    Segment::plain(exp.to_string()),
+                    Segment::plain(g.to_string()),
+                ],
             ),
-            format!(
-                "Remove the extra {} to match the expected signature.",
-                if g - exp == 1 {
-                    "argument"
-                } else {
-                    "arguments"
-                }
+            StyledText::from(
+                format!(
+                    "Remove the extra {} to match the expected signature.",
+                    if g - exp == 1 {
+                        "argument"
+                    } else {
+                        "arguments"
+                    }
+                )
+                .as_str(),
             ),
         ),
         _ => (
-            format!(
+            StyledText::format(
                 "Check if all required arguments are provided when invoking {}",
-                fn_name.red().bold()
+                [Segment::identifier(&fn_name)],
             ),
-            format!(
-                "Ensure the correct number of arguments are passed to `{}`.",
-                fn_name.red().bold()
+            StyledText::format(
+                "Ensure the correct number of arguments are passed to {}.",
+                [Segment::identifier(fn_name)],
             ),
         ),
     };
 
     Some(Suggestion {
-        suggestions: vec![suggestion],
-        help:        Some(help),
-        span:        None,
+        label: Some(suggestion),
+        help: Some(help),
+        span: None,
+        ..Default::default()
     })
 }
 
@@ -560,11 +755,13 @@ fn suggest_no_implicit_any(err: &TsError) -> Option<Suggestion> {
     let param_name = extract_first_quoted(&err.message)?;
 
     Some(Suggestion {
-        suggestions: vec![format!("{} is implicitly `any`.", param_name.red().bold())],
-        help:        Some(
-            "Consider adding type annotations to avoid implicit 'any' types.".to_string(),
-        ),
-        span:        None,
+        label: Some(StyledText::format(
+            "{} is implicitly `any`.",
+            [Segment::identifier(param_name)],
+        )),
+        help: Some("Consider adding type annotations to avoid implicit 'any' types.".into()),
+        span: None,
+        ..Default::default()
     })
 }
 
@@ -573,40 +770,43 @@ fn suggest_property_missing_in_type(err: &TsError, tokens: &[Token]) -> Option<S
         let var_name = extract_identifier_or_default(err, tokens, "");
 
         Some(Suggestion {
-            suggestions: vec![format!(
-                "Verify that `{}` matches the annotated type `{}`.",
-                var_name.red().bold().italic(),
-                type_name.red().bold()
-            )],
-            help:        Some(format!(
-                "Ensure that `{}` has all required properties defined in the type `{}`.",
-                var_name.red().bold().italic(),
-                type_name.red().bold()
+            label: Some(StyledText::format(
+                "Verify that {} matches the annotated type {}.",
+                [
+                    Segment::identifier(&var_name),
+                    Segment::type_name(&type_name),
+                ],
+            )),
+            help: Some(StyledText::format(
+                "Ensure that {} has all required properties defined in the type {}.",
+                [Segment::identifier(var_name), Segment::type_name(type_name)],
             )),
-            span:        None,
+            span: None,
+            ..Default::default()
         })
     } else {
         Some(Suggestion {
-            suggestions: vec![
+            label: Some(
                 "Verify that the object structure includes all required members of the specified type."
-                    .to_string()
-            ],
+                    .into()
+            ),
             help: Some(
-                "Ensure the object has all required properties defined in the type."
-                    .to_string(),
+                "Ensure the object has all required properties defined in the type.".into(),
             ),
             span: None,
+            ..Default::default()
         })
     }
 }
 
 fn suggest_unintentional_comparison() -> Option<Suggestion> {
     Some(Suggestion {
-        suggestions: vec![
-            "Impossible to compare as left side value is narrowed to a single value.".to_string(),
-        ],
-        help:        Some("Review the comparison logic to ensure it makes sense.".to_string()),
-        span:        None,
+        label: Some(
+            "Impossible to compare as left side value is narrowed to a single value.".into(),
+        ),
+        help: Some("Review the comparison logic to ensure it makes sense.".into()),
+        span: None,
+        ..Default::default()
     })
 }
 
@@ -614,34 +814,41 @@ fn suggest_property_does_not_exist(err: &TsError) -> Option<Suggestion> {
     let property_name = extract_first_quoted(&err.message)?;
     let type_name = extract_second_quoted(&err.message)?;
 
-    let mut sug_vec = Vec::new();
-    sug_vec.push(format!(
-        "Property `{}` is not found on type `{}`.",
-        property_name.red().bold(),
-        type_name.red().bold()
-    ));
+    let label = StyledText::format(
+        "Property {} is not found on type {}.",
+        [
+            Segment::identifier(property_name),
+            Segment::type_name(type_name),
+        ],
+    );
+
+    let mut notes = Vec::new();
 
-    if err.message.contains("The intersection '") {
-        let intersection = extract_third_quoted(&err.message)?;
+    let message = err.full_message();
+    if message.contains("The intersection '") {
+        let intersection = extract_third_quoted(&message)?;
 
-        let reduced_type = extract_quoted_value(&err.message, 7)?;
-        let property = extract_quoted_value(&err.message, 9)?;
+        let reduced_type = extract_quoted_value(&message, 7)?;
+        let property = extract_quoted_value(&message, 9)?;
 
-        sug_vec.push( format!(
-            "`{}` is resolved to `{}` as `{}` has multiple owners and is private to one or more of them whilst accessing as if it were public.",
-            intersection.red().bold(),
-            reduced_type.red().bold(),
-            property.red().bold()
+        notes.push(StyledText::format(
+            "{} is resolved to {} as {} has multiple owners and is private to one or more of them whilst accessing as if it were public.",
+            [
+                Segment::type_name(intersection),
+                Segment::type_name(reduced_type),
+                Segment::identifier(property),
+            ],
         ));
     }
 
     Some(Suggestion {
-        suggestions: sug_vec,
-        help:        Some(
+        label: Some(label),
+        notes,
+        help: Some(
             "Ensure the property exists on the type or adjust your code to avoid accessing it."
-                .to_string(),
+                .into(),
         ),
-        span:        None,
+        ..Default::default()
     })
 }
 
@@ -649,15 +856,16 @@ fn suggest_possibly_undefined(err: &TsError) -> Option<Suggestion> {
     let possible_undefined_var = extract_first_quoted(&err.message)?;
 
     Some(Suggestion {
-        suggestions: vec![format!(
+        label: Some(StyledText::format(
             "{} may be `undefined` here.",
-            possible_undefined_var.red().bold()
-        )],
-        help:        Some(format!(
-            "Consider optional chaining or an explicit check before attempting to access `{}`",
-            possible_undefined_var.red().bold()
+            [Segment::identifier(&possible_undefined_var)],
         )),
-        span:        None,
+        help: Some(StyledText::format(
+            "Consider optional chaining or an explicit check before attempting to access {}",
+            [Segment::identifier(possible_undefined_var)],
+        )),
+        span: None,
+        ..Default::default()
     })
 }
 
@@ -666,71 +874,78 @@ fn suggest_direct_cast_mistaken(err: &TsError) -> Option<Suggestion> {
     let cast_to_type = extract_second_quoted(&err.message)?;
 
     Some(Suggestion {
-        suggestions: vec![format!(
-            "Directly casting from `{}` to `{}` can be unsafe or mistaken, as both types do not overlap sufficiently.",
-            cast_from_type.yellow().bold(),
-            cast_to_type.yellow().bold()
-        )],
-        help:        Some(format!(
-            "Consider using type guards or intermediate conversions to ensure type safety when casting from `{}` to `{}`, only intermediately cast `as unknown` if this is desired.",
-            cast_from_type.yellow().bold(),
-            cast_to_type.yellow().bold()
+        label: Some(StyledText::format(
+            "Directly casting from {} to {} can be unsafe or mistaken, as both types do not overlap sufficiently.",
+            [
+                Segment::type_name(&cast_from_type),
+                Segment::type_name(&cast_to_type),
+            ],
+        )),
+        help: Some(StyledText::format(
+            "Consider using type guards or intermediate conversions to ensure type safety when casting from {} to {}, only intermediately cast `as unknown` if this is desired.",
+            [
+                Segment::type_name(cast_from_type),
+                Segment::type_name(cast_to_type),
+            ],
         )),
-        span:        None,
+        span: None,
+        ..Default::default()
     })
 }
 
 fn suggest_spread_tuple() -> Option<Suggestion> {
     Some(Suggestion {
-        suggestions: vec![
-            "The argument being spread must be a tuple type or a `spreadable` type, or the function must allow for dynamic argument counts.".to_string(),
-        ],
+        label: Some(
+            "The argument being spread must be a tuple type or a `spreadable` type, or the function must allow for dynamic argument counts.".into(),
+        ),
         help: Some(
             "Ensure that the argument being spread is a tuple type or that the function accepts dynamic arguments."
-                .to_string(),
+                .into(),
         ),
         span: None,
+        ..Default::default()
     })
 }
 
 fn suggest_right_arithmetic(_err: &TsError) -> Option<Suggestion> {
     Some(Suggestion {
-        suggestions: vec![
+        label: Some(
             "The right-hand side of any arithmetic operation must be a number or enumerable."
-                .to_string(),
-        ],
+                .into(),
+        ),
         help: Some(
             "Ensure that the value on the right side of the arithmetic operator is of type `number`, `bigint` or an enum member."
-                .to_string(),
+                .into(),
         ),
         span: None,
+        ..Default::default()
     })
 }
 
 fn suggest_left_arithmetic(_err: &TsError) -> Option<Suggestion> {
     Some(Suggestion {
-        suggestions: vec![
+        label: Some(
             "The left-hand side of any arithmetic operation must be a number or enumerable."
-                .to_string(),
-        ],
+                .into(),
+        ),
         help: Some(
             "Ensure that the value on the left side of the arithmetic operator is of type `number`, `bigint` or an enum member."
-                .to_string(),
+                .into(),
         ),
         span: None,
+        ..Default::default()
     })
 }
 
 fn suggest_incompatible_overload(_err: &TsError) -> Option<Suggestion> {
     Some(Suggestion {
-        suggestions: vec![
-            "The provided arguments do not match any overload of the function.".to_string(),
-        ],
+        label: Some("The provided arguments do not match any overload of the function.".into()),
         help: Some(
             "Check the function overloads and ensure that this signature adheres to the parent signature."
-                .to_string(),
+                .into(),
         ),
         span: None,
+        ..Default::default()
     })
 }
 
@@ -738,15 +953,16 @@ fn suggest_invalid_shadow(err: &TsError) -> Option<Suggestion> {
     let var_name = extract_first_quoted(&err.message)?;
 
     Some(Suggestion {
-        suggestions: vec![format!(
-            "Declared variable `{}` can not shadow another variable in this scope.",
-            var_name.red().bold()
-        )],
-        help:        Some(format!(
-            "Consider renaming the invalid shadowed variable `{}`.",
-            var_name.red().bold()
+        label: Some(StyledText::format(
+            "Declared variable {} can not shadow another variable in this scope.",
+            [Segment::identifier(&var_name)],
+        )),
+        help: Some(StyledText::format(
+            "Consider renaming the invalid shadowed variable {}.",
+            [Segment::identifier(var_name)],
         )),
-        span:        None,
+        span: None,
+        ..Default::default()
     })
 }
 
@@ -754,15 +970,16 @@ fn suggest_nonexistent_module(err: &TsError) -> Option<Suggestion> {
     let module_name = extract_first_quoted(&err.message)?;
 
     Some(Suggestion {
-        suggestions: vec![format!(
-            "Module `{}` does not exist.",
-            module_name.red().bold()
-        )],
-        help:        Some(format!(
-            "Ensure that the module `{}` is installed and the import path is correct.",
-            module_name.red().bold(),
+        label: Some(StyledText::format(
+            "Module {} does not exist.",
+            [Segment::identifier(&module_name)],
+        )),
+        help: Some(StyledText::format(
+            "Ensure that the module {} is installed and the import path is correct.",
+            [Segment::identifier(module_name)],
         )),
-        span:        None,
+        span: None,
+        ..Default::default()
     })
 }
 
@@ -770,68 +987,80 @@ fn suggest_readonly_property(err: &TsError) -> Option<Suggestion> {
     let property_name = extract_first_quoted(&err.message)?;
 
     Some(Suggestion {
-        suggestions: vec![format!(
-            "Property `{}` is readonly and thus can not be re-assigned.",
-            property_name.red().bold()
-        )],
-        help:        Some(format!(
-            "Consider removing the assignment to the read-only property `{}` or changing its declaration to be mutable.",
-            property_name.red().bold()
+        label: Some(StyledText::format(
+            "Property {} is readonly and thus can not be re-assigned.",
+            [Segment::identifier(&property_name)],
         )),
-        span:        None,
+        help: Some(StyledText::format(
+            "Consider removing the assignment to the read-only property {} or changing its declaration to be mutable.",
+            [Segment::identifier(property_name)],
+        )),
+        span: None,
+        ..Default::default()
     })
 }
 
 fn suggest_incorrect_interface(err: &TsError) -> Option<Suggestion> {
-    let class_name = extract_first_quoted(&err.message)?;
-    let interface_name = extract_second_quoted(&err.message)?;
-    let missing_property = extract_third_quoted(&err.message)?;
+    let message = err.full_message();
+    let class_name = extract_first_quoted(&message)?;
+    let interface_name = extract_second_quoted(&message)?;
+    let missing_property = extract_third_quoted(&message)?;
 
     Some(Suggestion {
-        suggestions: vec![format!(
-            "Class `{}` does not implement `{}` from interface `{}`.",
-            class_name.red().bold(),
-            missing_property.red().bold(),
-            interface_name.red().bold()
-        )],
-        help:        Some(format!(
-            "Ensure that `{}` provides all required properties and methods defined in the interface `{}`.",
-            class_name.red().bold(),
-            interface_name.red().bold()
+        label: Some(StyledText::format(
+            "Class {} does not implement {} from interface {}.",
+            [
+                Segment::type_name(&class_name),
+                Segment::identifier(missing_property),
+                Segment::type_name(&interface_name),
+            ],
         )),
-        span:        None,
+        help: Some(StyledText::format(
+            "Ensure that {} provides all required properties and methods defined in the interface {}.",
+            [
+                Segment::type_name(class_name),
+                Segment::type_name(interface_name),
+            ],
+        )),
+        span: None,
+        ..Default::default()
     })
 }
 
 fn suggest_property_not_assignable(err: &TsError) -> Option<Suggestion> {
-    let property = extract_first_quoted(&err.message)?;
-    let impl_type = extract_second_quoted(&err.message)?;
-    let base_type = extract_third_quoted(&err.message)?;
-    let property_impl_type = extract_quoted_value(&err.message, 7)?;
-    let property_base_type = extract_quoted_value(&err.message, 9)?;
+    let message = err.full_message();
+    let property = extract_first_quoted(&message)?;
+    let impl_type = extract_second_quoted(&message)?;
+    let base_type = extract_third_quoted(&message)?;
+    let property_impl_type = extract_quoted_value(&message, 7)?;
+    let property_base_type = extract_quoted_value(&message, 9)?;
 
     Some(Suggestion {
-        suggestions: vec![
-            format!(
-                "Property `{}` in class `{}` is not assignable to the same property in base class `{}`.",
-                property.red().bold(),
-                impl_type.red().bold(),
-                base_type.red().bold()
-            ),
-            format!(
-                "Property `{}` is implemented as type `{}` but defined as `{}`.",
-                property.red().bold(),
-                property_impl_type.red().bold(),
-                property_base_type.green().bold()
-            ),
-        ],
-        help:        Some(format!(
-            "Ensure that the type of property `{}` in class `{}` is compatible with the type defined in base class `{}`.",
-            property.red().bold(),
-            impl_type.red().bold(),
-            base_type.red().bold()
+        label: Some(StyledText::format(
+            "Property {} in class {} is not assignable to the same property in base class {}.",
+            [
+                Segment::identifier(&property),
+                Segment::type_name(&impl_type),
+                Segment::type_name(&base_type),
+            ],
         )),
-        span:        None,
+        notes: vec![StyledText::format(
+            "Property {} is implemented as type {} but defined as {}.",
+            [
+                Segment::identifier(&property),
+                Segment::type_name(property_impl_type),
+                Segment::suggested(property_base_type),
+            ],
+        )],
+        help: Some(StyledText::format(
+            "Ensure that the type of property {} in class {} is compatible with the type defined in base class {}.",
+            [
+                Segment::identifier(property),
+                Segment::type_name(impl_type),
+                Segment::type_name(base_type),
+            ],
+        )),
+        ..Default::default()
     })
 }
 
@@ -839,26 +1068,28 @@ fn suggest_cannot_find_identifier(err: &TsError) -> Option<Suggestion> {
     let identifier = extract_first_quoted(&err.message)?;
 
     Some(Suggestion {
-        suggestions: vec![format!(
-            "Identifier `{}` can not be found in the current scope.",
-            identifier.red().bold()
-        )],
-        help:        Some(format!(
-            "Ensure that `{}` is declared and accessible in the current scope or remove this reference.",
-            identifier.red().bold()
+        label: Some(StyledText::format(
+            "Identifier {} can not be found in the current scope.",
+            [Segment::identifier(&identifier)],
+        )),
+        help: Some(StyledText::format(
+            "Ensure that {} is declared and accessible in the current scope or remove this reference.",
+            [Segment::identifier(identifier)],
         )),
-        span:        None,
+        span: None,
+        ..Default::default()
     })
 }
 
 fn suggest_missing_return(_err: &TsError) -> Option<Suggestion> {
     Some(Suggestion {
-        suggestions: vec!["A return value is missing where one is expected.".to_string()],
+        label: Some("A return value is missing where one is expected.".into()),
         help: Some(
             "A function that declares a return type must return a value of that type on all branches."
-                .to_string(),
+                .into(),
         ),
         span: None,
+        ..Default::default()
     })
 }
 
@@ -866,15 +1097,16 @@ fn suggest_uncallable_expression(err: &TsError) -> Option<Suggestion> {
     let expr = extract_first_quoted(&err.message)?;
 
     Some(Suggestion {
-        suggestions: vec![format!(
-            "Expression `{}` not can not be invoked or called.",
-            expr.red().bold()
-        )],
-        help:        Some(format!(
-            "Ensure that `{}` is a function or has a callable signature before invoking it.",
-            expr.red().bold()
+        label: Some(StyledText::format(
+            "Expression {} not can not be invoked or called.",
+            [Segment::identifier(&expr)],
+        )),
+        help: Some(StyledText::format(
+            "Ensure that {} is a function or has a callable signature before invoking it.",
+            [Segment::identifier(expr)],
         )),
-        span:        None,
+        span: None,
+        ..Default::default()
     })
 }
 
@@ -882,12 +1114,13 @@ fn suggest_invalid_index_type(err: &TsError) -> Option<Suggestion> {
     let index_type = extract_first_quoted(&err.message)?;
 
     Some(Suggestion {
-        suggestions: vec![format!(
-            "`{}` can not be used as an index accessor.",
-            index_type.red().bold()
-        )],
-        help: Some("Ensure that the index type is `number`, `string`, `symbol` or a compatible index type.".to_string()),
+        label: Some(StyledText::format(
+            "{} can not be used as an index accessor.",
+            [Segment::type_name(index_type)],
+        )),
+        help: Some("Ensure that the index type is `number`, `string`, `symbol` or a compatible index type.".into()),
         span: None,
+        ..Default::default()
     })
 }
 
@@ -898,12 +1131,13 @@ fn suggest_invalid_index_signature(err: &TsError, tokens: &[Token]) -> Option<Su
     let span = token.map(|t| t.start..t.end)?;
 
     Some(Suggestion {
-        suggestions: vec![format!(
-            "`{}` is not a valid index type.",
-            span_text.red().bold()
-        )],
-        help: Some("Ensure that the index type is `number`, `string`, `symbol`, `template literal` or a compatible index type.".to_string()),
+        label: Some(StyledText::format(
+            "{} is not a valid index type.",
+            [Segment::type_name(span_text)],
+        )),
+        help: Some("Ensure that the index type is `number`, `string`, `symbol`, `template literal` or a compatible index type.".into()),
         span: Some(span),
+        ..Default::default()
     })
 }
 
@@ -913,18 +1147,23 @@ fn suggest_typo_property(err: &TsError) -> Option<Suggestion> {
     let suggested_property_name = extract_third_quoted(&err.message)?;
 
     Some(Suggestion {
-        suggestions: vec![format!(
-            "Property `{}` does not exist on type `{}`. Try `{}` instead",
-            property_name.red().bold(),
-            type_name.yellow().bold(),
-            suggested_property_name.green().bold()
-        )],
-        help:        Some(format!(
-            "Check for typos in the property name `{}` or ensure that it is defined on type `{}`.",
-            property_name.red().bold(),
-            type_name.red().bold()
+        label: Some(StyledText::format(
+            "Property {} does not exist on type {}. Try {} instead",
+            [
+                Segment::identifier(&property_name),
+                Segment::type_name(&type_name),
+                Segment::suggested(suggested_property_name),
+            ],
         )),
-        span:        None,
+        help: Some(StyledText::format(
+            "Check for typos in the property name {} or ensure that it is defined on type {}.",
+            [
+                Segment::identifier(property_name),
+                Segment::type_name(type_name),
+            ],
+        )),
+        span: None,
+        ..Default::default()
     })
 }
 
@@ -932,15 +1171,16 @@ fn suggest_possibly_null(err: &TsError) -> Option<Suggestion> {
     let possible_null_var = extract_first_quoted(&err.message)?;
 
     Some(Suggestion {
-        suggestions: vec![format!(
+        label: Some(StyledText::format(
             "{} may be `null` here.",
-            possible_null_var.red().bold()
-        )],
-        help:        Some(format!(
-            "Consider optional chaining or an explicit null check before attempting to access `{}`",
-            possible_null_var.red().bold()
+            [Segment::identifier(&possible_null_var)],
+        )),
+        help: Some(StyledText::format(
+            "Consider optional chaining or an explicit null check before attempting to access {}",
+            [Segment::identifier(possible_null_var)],
         )),
-        span:        None,
+        span: None,
+        ..Default::default()
     })
 }
 
@@ -948,76 +1188,81 @@ fn suggest_object_unknown(err: &TsError) -> Option<Suggestion> {
     let unknown_var = extract_first_quoted(&err.message)?;
 
     Some(Suggestion {
-        suggestions: vec![format!(
+        label: Some(StyledText::format(
             "{} is of type `unknown`.",
-            unknown_var.red().bold()
-        )],
-        help:        Some(format!(
-            "Use type guards, type assertions, or narrow the type of `{}` before accessing its properties.",
-            unknown_var.red().bold()
+            [Segment::identifier(&unknown_var)],
+        )),
+        help: Some(StyledText::format(
+            "Use type guards, type assertions, or narrow the type of {} before accessing its properties.",
+            [Segment::identifier(unknown_var)],
         )),
-        span:        None,
+        span: None,
+        ..Default::default()
     })
 }
 
 fn suggest_unterminated_string(err: &TsError) -> Option<Suggestion> {
     let literal = extract_first_quoted(&err.message)?;
     Some(Suggestion {
-        suggestions: vec![format!(
+        label: Some(StyledText::format(
             "String {} is missing \" to close the string.",
-            literal.red().bold()
-        )],
-        help:        Some(
-            "Ensure that all string literals are properly closed with matching quotes.".to_string(),
+            [Segment::identifier(literal)],
+        )),
+        help: Some(
+            "Ensure that all string literals are properly closed with matching quotes.".into(),
         ),
-        span:        None,
+        span: None,
+        ..Default::default()
     })
 }
 
 fn suggest_identifier_expected() -> Option<Suggestion> {
     Some(Suggestion {
-        suggestions: vec!["An identifier was expected at this location in the code.".to_string()],
+        label: Some("An identifier was expected at this location in the code.".into()),
         help: Some(
             "Check the syntax near this location to ensure that an identifier is provided where required."
-                .to_string(),
+                .into(),
         ),
         span: None,
+        ..Default::default()
     })
 }
 
 fn suggest_disallowed_comma() -> Option<Suggestion> {
     Some(Suggestion {
-        suggestions: vec!["Trailing commas are not allowed in this context.".to_string()],
-        help:        Some("Remove the trailing comma to resolve the syntax error.".to_string()),
-        span:        None,
+        label: Some("Trailing commas are not allowed in this context.".into()),
+        help: Some("Remove the trailing comma to resolve the syntax error.".into()),
+        span: None,
+        ..Default::default()
     })
 }
 
 fn suggest_spread_parameter_last() -> Option<Suggestion> {
     Some(Suggestion {
-        suggestions: vec![
-            "A spread parameter must be the last parameter in a function signature.".to_string(),
-        ],
-        help:        Some(
-            "Move the `...` parameter to the end of the list of parameters.".to_string(),
+        label: Some(
+            "A spread parameter must be the last parameter in a function signature.".into(),
         ),
-        span:        None,
+        help: Some("Move the `...` parameter to the end of the list of parameters.".into()),
+        span: None,
+        ..Default::default()
     })
 }
 
 fn suggest_expression_expected() -> Option<Suggestion> {
     Some(Suggestion {
-        suggestions: vec!["An expression was found but no value is assigned to it.".to_string()],
-        help:        Some("Assign a value to the expression.".to_string()),
-        span:        None,
+        label: Some("An expression was found but no value is assigned to it.".into()),
+        help: Some("Assign a value to the expression.".into()),
+        span: None,
+        ..Default::default()
     })
 }
 
 fn suggest_unique_members() -> Option<Suggestion> {
     Some(Suggestion {
-        suggestions: vec!["Consider removing or renaming one of the object members".to_string()],
-        help:        Some("An object may contain a member name once.".to_string()),
-        span:        None,
+        label: Some("Consider removing or renaming one of the object members".into()),
+        help: Some("An object may contain a member name once.".into()),
+        span: None,
+        ..Default::default()
     })
 }
 
@@ -1025,37 +1270,36 @@ fn suggest_uninitialized_const(err: &TsError, tokens: &[Token]) -> Option<Sugges
     let (name, span) = find_identifier_after_keyword(tokens, err.line, "const")?;
 
     Some(Suggestion {
-        suggestions: vec![format!("`{}` must be initialized", name.red().bold())],
-        help:        Some(format!(
-            "Initialize `{}` with a value",
-            name.yellow().bold()
+        label: Some(StyledText::format(
+            "{} must be initialized",
+            [Segment::identifier(&name)],
+        )),
+        help: Some(StyledText::format(
+            "Initialize {} with a value",
+            [Segment::identifier(name)],
         )),
-        span:        Some(span),
+        span: Some(span),
+        ..Default::default()
     })
 }
 
 fn suggest_yield_not_in_generator() -> Option<Suggestion> {
     Some(Suggestion {
-        suggestions: vec![format!(
-            "`{}` can only be used in generator functions",
-            "yield".red().bold()
-        )],
-        help:        Some(format!(
-            "use `{}` inside of `{}`",
-            "yield".yellow().bold(),
-            "function*".yellow().bold()
-        )),
-        span:        None,
+        label: Some("`yield` can only be used in generator functions".into()),
+        help: Some("use `yield` inside of `function*`".into()),
+        span: None,
+        ..Default::default()
     })
 }
 
 fn suggest_jsx_flag() -> Option<Suggestion> {
     Some(Suggestion {
-        suggestions: vec!["JSX can not be used.".to_string()],
-        help:        Some(
-            "Enable the JSX flag in your TypeScript configuration to use JSX syntax.".to_string(),
+        label: Some("JSX can not be used.".into()),
+        help: Some(
+            "Enable the JSX flag in your TypeScript configuration to use JSX syntax.".into(),
         ),
-        span:        None,
+        span: None,
+        ..Default::default()
     })
 }
 
@@ -1063,12 +1307,16 @@ fn suggest_declared_unused(err: &TsError) -> Option<Suggestion> {
     let unused_decl = extract_first_quoted(&err.message)?;
 
     Some(Suggestion {
-        suggestions: vec![format!("`{}` is unused", unused_decl.red().bold())],
-        help:        Some(format!(
-            "Consider removing the reference to `{}`",
-            unused_decl.yellow().bold()
+        label: Some(StyledText::format(
+            "{} is unused",
+            [Segment::identifier(&unused_decl)],
+        )),
+        help: Some(StyledText::format(
+            "Consider removing the reference to {}",
+            [Segment::identifier(unused_decl)],
         )),
-        span:        None,
+        span: None,
+        ..Default::default()
     })
 }
 
@@ -1077,36 +1325,39 @@ fn suggest_no_exported_member(err: &TsError) -> Option<Suggestion> {
     let potential_correction = extract_quoted_value(&err.message, 5);
 
     Some(Suggestion {
-        suggestions: vec![format!(
-            "`{}` is not exported from the module.",
-            non_exported_member?.red().bold()
-        )],
-        help:        Some(format!(
-            "Did you mean to import `{}`?",
-            potential_correction?.green().bold()
+        label: Some(StyledText::format(
+            "{} is not exported from the module.",
+            [Segment::identifier(non_exported_member?)],
+        )),
+        help: Some(StyledText::format(
+            "Did you mean to import {}?",
+            [Segment::suggested(potential_correction?)],
         )),
-        span:        None,
+        span: None,
+        ..Default::default()
     })
 }
 
 fn suggest_imported_unused() -> Option<Suggestion> {
     Some(Suggestion {
-        suggestions: vec!["This import is unused".to_string()],
-        help:        Some("Consider removing it".to_string()),
-        span:        None,
+        label: Some("This import is unused".into()),
+        help: Some("Consider removing it".into()),
+        span: None,
+        ..Default::default()
     })
 }
 
 fn suggest_invalid_default_import() -> Option<Suggestion> {
     Some(Suggestion {
-        suggestions: vec![format!(
-            "`{}` is missing from compiler configuration, default imports are not allowed.",
-            "esModuleInterop".red().bold()
-        )],
-        help:        Some(format!(
-            "Enable compiler flag `{}` to allow default imports for this module.",
-            "esModuleInterop".yellow().bold()
-        )),
-        span:        None,
+        label: Some(
+            "`esModuleInterop` is missing from compiler configuration, default imports are not allowed."
+                .into(),
+        ),
+        help:  Some(
+            "Enable compiler flag `esModuleInterop` to allow default imports for this module."
+                .into(),
+        ),
+        span:  None,
+        ..Default::default()
     })
 }
@@ -1,3 +1,7 @@
+use super::core::Severity;
+
+/// A tsc error code. Variants that stand for several codes keep the number tsc reported, so the
+/// code is always printed the way tsc printed it.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ErrorCode {
     // type errors
@@ -5,14 +9,14 @@ pub enum ErrorCode {
     InlineTypeMismatch,
     PropertyMissingInType,
     PropertyDoesNotExist,
-    TypeAssertionInJsNotAllowed,
+    TypeAssertionInJsNotAllowed(u16),
     MappedTypeMustBeStatic,
     TypesOfPropertyAreIncompatible,
 
     // null safety
-    ObjectIsPossiblyNull,
-    ObjectIsPossiblyUndefined,
-    ObjectIsUnknown,
+    ObjectIsPossiblyNull(u16),
+    ObjectIsPossiblyUndefined(u16),
+    ObjectIsUnknown(u16),
 
     // Function/parameter errors (TS25xx range)
     MissingParameters,
@@ -31,7 +35,7 @@ pub enum ErrorCode {
 
     // Module/import errors (TS23xx, TS6xxx)
     NonExistentModuleImport,
-    NoExportedMember,
+    NoExportedMember(u16),
     InvalidDefaultImport,
     DuplicateFunctionDeclaration,
     InvalidOperatorUsage,
@@ -42,7 +46,7 @@ pub enum ErrorCode {
     ReadonlyPropertyAssignment,
 
     // Misc
-    NoImplicitAny,
+    NoImplicitAny(u16),
     UnintentionalComparison,
     DirectCastPotentiallyMistaken,
     SpreadArgumentMustBeTupleType,
@@ -70,24 +74,36 @@ pub enum ErrorCode {
     JsxElementIsNotCallable,
     InvalidJsxConfigurationUmd,
 
+    // Compiler configuration errors, usually reported without a location
+    UnknownCompilerOption(u16),
+    InvalidCompilerOptionValue(u16),
+    CannotReadFile(u16),
+    TsconfigNotFound(u16),
+    NoInputsFound,
+
     /// Catch-all for unsupported error codes
-    Unsupported(u16),
+    Unsupported(u32),
 }
 
 impl ErrorCode {
     /// Create an `ErrorCode` from a string represenatation like "TS2322"
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(code: &str) -> Self {
         match code {
             "TS2322" => ErrorCode::TypeMismatch,
             "TS2345" => ErrorCode::InlineTypeMismatch,
             "TS2554" => ErrorCode::MissingParameters,
-            "TS7006" | "TS7044" => ErrorCode::NoImplicitAny,
+            "TS7006" => ErrorCode::NoImplicitAny(7006),
+            "TS7044" => ErrorCode::NoImplicitAny(7044),
             "TS2741" => ErrorCode::PropertyMissingInType,
             "TS2367" => ErrorCode::UnintentionalComparison,
-            "TS18046" | "TS2571" => ErrorCode::ObjectIsUnknown,
+            "TS18046" => ErrorCode::ObjectIsUnknown(18046),
+            "TS2571" => ErrorCode::ObjectIsUnknown(2571),
             "TS2339" => ErrorCode::PropertyDoesNotExist,
-            "TS2532" | "TS18048" => ErrorCode::ObjectIsPossiblyUndefined,
-            "TS2531" | "TS18047" => ErrorCode::ObjectIsPossiblyNull,
+            "TS2532" => ErrorCode::ObjectIsPossiblyUndefined(2532),
+            "TS18048" => ErrorCode::ObjectIsPossiblyUndefined(18048),
+            "TS2531" => ErrorCode::ObjectIsPossiblyNull(2531),
+            "TS18047" => ErrorCode::ObjectIsPossiblyNull(18047),
             "TS2352" => ErrorCode::DirectCastPotentiallyMistaken,
             "TS2556" => ErrorCode::SpreadArgumentMustBeTupleType,
             "TS2362" => ErrorCode::LeftSideArithmeticMustBeEnumberable,
@@ -115,11 +131,13 @@ impl ErrorCode {
             "TS1163" => ErrorCode::YieldNotInGenerator,
             "TS17004" => ErrorCode::JsxFlagNotProvided,
             "TS6133" => ErrorCode::DeclaredButNeverUsed,
-            "TS2305" | "TS2724" => ErrorCode::NoExportedMember,
+            "TS2305" => ErrorCode::NoExportedMember(2305),
+            "TS2724" => ErrorCode::NoExportedMember(2724),
             "TS6192" => ErrorCode::ImportedButNeverUsed,
             "TS1259" => ErrorCode::InvalidDefaultImport,
             "TS95050" => ErrorCode::UnreachableCode,
-            "TS8016" | "TS8010" => ErrorCode::TypeAssertionInJsNotAllowed,
+            "TS8016" => ErrorCode::TypeAssertionInJsNotAllowed(8016),
+            "TS8010" => ErrorCode::TypeAssertionInJsNotAllowed(8010),
             "TS7061" => ErrorCode::MappedTypeMustBeStatic,
             "TS7053" => ErrorCode::ElementImplicitAnyInvalidIndexTypeForObject,
             "TS7026" => ErrorCode::MissingJsxIntrinsicElementsDeclaration,
@@ -132,10 +150,19 @@ impl ErrorCode {
             "TS2604" => ErrorCode::JsxElementIsNotCallable,
             "TS2686" => ErrorCode::InvalidJsxConfigurationUmd,
             "TS2326" => ErrorCode::TypesOfPropertyAreIncompatible,
+            "TS5023" => ErrorCode::UnknownCompilerOption(5023),
+            "TS5025" => ErrorCode::UnknownCompilerOption(5025),
+            "TS5024" => ErrorCode::InvalidCompilerOptionValue(5024),
+            "TS6046" => ErrorCode::InvalidCompilerOptionValue(6046),
+            "TS5083" => ErrorCode::CannotReadFile(5083),
+            "TS6053" => ErrorCode::CannotReadFile(6053),
+            "TS5057" => ErrorCode::TsconfigNotFound(5057),
+            "TS5058" => ErrorCode::TsconfigNotFound(5058),
+            "TS18003" => ErrorCode::NoInputsFound,
 
             other => {
                 if let Some(num_str) = other.strip_prefix("TS")
-                    && let Ok(num) = num_str.parse::<u16>()
+                    && let Ok(num) = num_str.parse::<u32>()
                 {
                     return ErrorCode::Unsupported(num);
                 }
@@ -144,19 +171,173 @@ impl ErrorCode {
         }
     }
 
+    /// Severity the code is reported with when tsc reports it as an error. Unused declarations
+    /// are warnings and unreachable code is advice, as in editors.
+    pub fn default_severity(&self) -> Option<Severity> {
+        match self {
+            ErrorCode::DeclaredButNeverUsed | ErrorCode::ImportedButNeverUsed => {
+                Some(Severity::Warning)
+            }
+            ErrorCode::UnreachableCode => Some(Severity::Advice),
+            _ => None,
+        }
+    }
+
+    /// Help that applies to every error of the code, suggestions give help specific to one error
+    pub fn help(&self) -> &'static str {
+        match self {
+            ErrorCode::TypeMismatch => {
+                "Make the assigned value match the declared type, or convert it explicitly."
+            }
+            ErrorCode::InlineTypeMismatch => {
+                "Pass an argument of the parameter type, or convert it before the call."
+            }
+            ErrorCode::PropertyMissingInType => {
+                "Add the missing required properties, or mark them optional in the type."
+            }
+            ErrorCode::PropertyDoesNotExist => {
+                "Check the property name, or add the property to the type."
+            }
+            ErrorCode::TypeAssertionInJsNotAllowed(_) => {
+                "Use a JSDoc type annotation instead of TypeScript syntax in JavaScript files."
+            }
+            ErrorCode::MappedTypeMustBeStatic => "Use a static key type in the mapped type.",
+            ErrorCode::TypesOfPropertyAreIncompatible => {
+                "Make the property types of both sides compatible."
+            }
+            ErrorCode::ObjectIsPossiblyNull(_) => {
+                "Check for null before using the value, or use optional chaining."
+            }
+            ErrorCode::ObjectIsPossiblyUndefined(_) => {
+                "Check for undefined before using the value, or use optional chaining."
+            }
+            ErrorCode::ObjectIsUnknown(_) => "Narrow the value with a type guard before using it.",
+            ErrorCode::MissingParameters => {
+                "Pass every required argument, or make the parameters optional."
+            }
+            ErrorCode::IncompatibleOverload => {
+                "Make the implementation signature compatible with every overload."
+            }
+            ErrorCode::UncallableExpression => "Only call values whose type has a call signature.",
+            ErrorCode::CannotFindReference => "Check the spelling of the name, or declare it.",
+            ErrorCode::UnionTooComplex => {
+                "Simplify the union type, or split it into smaller named types."
+            }
+            ErrorCode::UnterminatedStringLiteral => {
+                "Close the string literal with a matching quote."
+            }
+            ErrorCode::IdentifierExpected => {
+                "Add the missing identifier, or remove the stray token."
+            }
+            ErrorCode::ExpressionExpected => "Complete the expression, or remove the stray token.",
+            ErrorCode::DisallowedTrailingComma => "Remove the trailing comma.",
+            ErrorCode::SpreadParameterMustBeLast => {
+                "Move the rest parameter to the end of the parameter list."
+            }
+            ErrorCode::UnexpectedKeywordOrIdentifier => {
+                "Check the syntax around the keyword or identifier."
+            }
+            ErrorCode::NonExistentModuleImport => {
+                "Check the module path, or install the package and its types."
+            }
+            ErrorCode::NoExportedMember(_) => {
+                "Import a member the module exports, or export it from the module."
+            }
+            ErrorCode::InvalidDefaultImport => "Use a named import, or enable esModuleInterop.",
+            ErrorCode::DuplicateFunctionDeclaration => {
+                "Rename or remove one of the function implementations."
+            }
+            ErrorCode::InvalidOperatorUsage => {
+                "Use the operator only with operand types it supports."
+            }
+            ErrorCode::IncorrectInterfaceImplementation => {
+                "Implement every member of the interface with a compatible type."
+            }
+            ErrorCode::PropertyInClassNotAssignableToBase => {
+                "Give the property a type compatible with the base class."
+            }
+            ErrorCode::ReadonlyPropertyAssignment => {
+                "Do not assign to readonly properties, create a new object instead."
+            }
+            ErrorCode::NoImplicitAny(_) => "Add a type annotation.",
+            ErrorCode::UnintentionalComparison => "Compare values whose types overlap.",
+            ErrorCode::DirectCastPotentiallyMistaken => {
+                "Convert the value, or cast through unknown when the cast is intended."
+            }
+            ErrorCode::SpreadArgumentMustBeTupleType => {
+                "Spread a tuple, or pass the arguments one by one."
+            }
+            ErrorCode::RightSideArithmeticMustBeEnumberable => {
+                "Use a number, bigint or enum on the right side of the operator."
+            }
+            ErrorCode::LeftSideArithmeticMustBeEnumberable => {
+                "Use a number, bigint or enum on the left side of the operator."
+            }
+            ErrorCode::InvalidShadowInScope => "Rename one of the declarations in the block.",
+            ErrorCode::CannotFindIdentifier => "Declare the name, or import it.",
+            ErrorCode::MissingReturnValue => "Return a value on every code path.",
+            ErrorCode::InvalidIndexType => "Index with a string, number or symbol key of the type.",
+            ErrorCode::InvalidIndexTypeSignature => {
+                "Use string, number, symbol or a template literal type in index signatures."
+            }
+            ErrorCode::ElementImplicitAnyInvalidIndexTypeForObject => {
+                "Index with a key of the type, or add an index signature."
+            }
+            ErrorCode::TypoPropertyOnType => "Check the spelling of the property.",
+            ErrorCode::UniqueObjectMemberNames => {
+                "Remove the duplicate property from the object literal."
+            }
+            ErrorCode::UninitializedConst => "Initialize the const where it is declared.",
+            ErrorCode::YieldNotInGenerator => "Only use yield inside generator functions.",
+            ErrorCode::DeclaredButNeverUsed => {
+                "Remove the unused declaration, or prefix it with an underscore."
+            }
+            ErrorCode::ImportedButNeverUsed => "Remove the unused import.",
+            ErrorCode::UnreachableCode => {
+                "Remove the unreachable code, or the statement that makes it unreachable."
+            }
+            ErrorCode::ConstEnumsDisallowed => "Use a regular enum, or disable isolatedModules.",
+            ErrorCode::JsxFlagNotProvided => "Set the jsx compiler option.",
+            ErrorCode::MissingJsxIntrinsicElementsDeclaration => {
+                "Install the JSX types of the framework, like @types/react."
+            }
+            ErrorCode::JsxModuleNotSet => "Set the jsx compiler option.",
+            ErrorCode::JsxElementIsNotCallable => {
+                "Only use components that return a valid JSX element."
+            }
+            ErrorCode::InvalidJsxConfigurationUmd => {
+                "Import React in the module, or use the react-jsx transform."
+            }
+            ErrorCode::UnknownCompilerOption(_) => {
+                "Check the spelling of the option, or whether it requires a newer TypeScript version."
+            }
+            ErrorCode::InvalidCompilerOptionValue(_) => "Use one of the values the option accepts.",
+            ErrorCode::CannotReadFile(_) => "Check that the file exists and is readable.",
+            ErrorCode::TsconfigNotFound(_) => "Check the path of the tsconfig.json.",
+            ErrorCode::NoInputsFound => {
+                "Check the include, files and exclude settings of the tsconfig.json."
+            }
+            ErrorCode::Unsupported(_) => "See the TypeScript documentation for this error code.",
+        }
+    }
+
     /// Create the strng representation like "TS2322" from an `ErrorCode`
     pub fn as_str(&self) -> &'static str {
         match self {
             ErrorCode::TypeMismatch => "TS2322",
             ErrorCode::InlineTypeMismatch => "TS2345",
             ErrorCode::MissingParameters => "TS2554",
-            ErrorCode::NoImplicitAny => "TS7006",
+            ErrorCode::NoImplicitAny(7044) => "TS7044",
+            ErrorCode::NoImplicitAny(_) => "TS7006",
             ErrorCode::PropertyMissingInType => "TS2741",
             ErrorCode::UnintentionalComparison => "TS2367",
             ErrorCode::PropertyDoesNotExist => "TS2339",
-            ErrorCode::ObjectIsPossiblyUndefined => "TS2532",
-            ErrorCode::ObjectIsPossiblyNull => "TS2531",
-            ErrorCode::ObjectIsUnknown => "TS18046",
+            ErrorCode::ObjectIsPossiblyUndefined(18048) => "TS18048",
+            ErrorCode::ObjectIsPossiblyUndefined(_) => "TS2532",
+            ErrorCode::ObjectIsPossiblyNull(18047) => "TS18047",
+            ErrorCode::ObjectIsPossiblyNull(_) => "TS2531",
+            ErrorCode::ObjectIsUnknown(2571) => "TS2571",
+            ErrorCode::ObjectIsUnknown(_) => "TS18046",
             ErrorCode::DirectCastPotentiallyMistaken => "TS2352",
             ErrorCode::SpreadArgumentMustBeTupleType => "TS2556",
             ErrorCode::RightSideArithmeticMustBeEnumberable => "TS2363",
@@ -185,10 +366,12 @@ impl ErrorCode {
             ErrorCode::JsxFlagNotProvided => "TS17004",
             ErrorCode::DeclaredButNeverUsed => "TS6133",
             ErrorCode::ImportedButNeverUsed => "TS6192",
-            ErrorCode::NoExportedMember => "TS2305",
+            ErrorCode::NoExportedMember(2724) => "TS2724",
+            ErrorCode::NoExportedMember(_) => "TS2305",
             ErrorCode::InvalidDefaultImport => "TS1259",
             ErrorCode::UnreachableCode => "TS95050",
-            ErrorCode::TypeAssertionInJsNotAllowed => "TS8016",
+            ErrorCode::TypeAssertionInJsNotAllowed(8010) => "TS8010",
+            ErrorCode::TypeAssertionInJsNotAllowed(_) => "TS8016",
             ErrorCode::MappedTypeMustBeStatic => "TS7061",
             ErrorCode::ElementImplicitAnyInvalidIndexTypeForObject => "TS7053",
             ErrorCode::MissingJsxIntrinsicElementsDeclaration => "TS7026",
@@ -201,6 +384,15 @@ impl ErrorCode {
             ErrorCode::JsxElementIsNotCallable => "TS2604",
             ErrorCode::InvalidJsxConfigurationUmd => "TS2686",
             ErrorCode::TypesOfPropertyAreIncompatible => "TS2326",
+            ErrorCode::UnknownCompilerOption(5025) => "TS5025",
+            ErrorCode::UnknownCompilerOption(_) => "TS5023",
+            ErrorCode::InvalidCompilerOptionValue(6046) => "TS6046",
+            ErrorCode::InvalidCompilerOptionValue(_) => "TS5024",
+            ErrorCode::CannotReadFile(6053) => "TS6053",
+            ErrorCode::CannotReadFile(_) => "TS5083",
+            ErrorCode::TsconfigNotFound(5058) => "TS5058",
+            ErrorCode::TsconfigNotFound(_) => "TS5057",
+            ErrorCode::NoInputsFound => "TS18003",
             ErrorCode::Unsupported(_) => {
                 // This will return a static string for known codes, but for unsupported codes,
                 // we return a dynamically allocated string. To keep the return type consistent,
@@ -213,6 +405,9 @@ impl ErrorCode {
 
 impl std::fmt::Display for ErrorCode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.as_str())
+        match self {
+            ErrorCode::Unsupported(code) => write!(f, "TS{}", code),
+            _ => write!(f, "{}", self.as_str()),
+        }
     }
 }
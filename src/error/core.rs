@@ -1,9 +1,303 @@
-/// Represents a TypeScript error from the compiler
-#[derive(Debug, Clone)]
-pub struct TsError {
+/// Category of a diagnostic as reported by tsc
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Category {
+    #[default]
+    Error,
+    Warning,
+    Message,
+}
+
+impl Category {
+    /// Parse the category keyword tsc prints in front of the code
+    pub fn from_keyword(category: &str) -> Option<Self> {
+        match category {
+            "error" => Some(Category::Error),
+            "warning" => Some(Category::Warning),
+            "message" => Some(Category::Message),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Category::Error => "error",
+            Category::Warning => "warning",
+            Category::Message => "message",
+        }
+    }
+}
+
+/// How serious a diagnostic is. Derived from the tsc category, but codes like unused
+/// declarations default to a lower one, see
+/// [`ErrorCode::default_severity`](super::codes::ErrorCode::default_severity).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    #[default]
+    Error,
+    Warning,
+    Advice,
+}
+
+impl Severity {
+    /// Severity of a code reported in the given category. A code default only ever lowers the
+    /// severity tsc reported.
+    pub fn of(code: super::codes::ErrorCode, category: Category) -> Self {
+        let reported = match category {
+            Category::Error => Severity::Error,
+            Category::Warning => Severity::Warning,
+            Category::Message => Severity::Advice,
+        };
+
+        code.default_severity()
+            .map_or(reported, |default| default.max(reported))
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Advice => "advice",
+        }
+    }
+}
+
+/// An elaboration tsc chains below a diagnostic message to explain it in more detail
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageChain {
+    pub message: String,
+    /// Nesting depth, 1 for direct elaborations of the diagnostic message
+    pub depth:   usize,
+    pub next:    Vec<MessageChain>,
+}
+
+/// A location tsc relates to a diagnostic, e.g. `'foo' is declared here.`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelatedInformation {
     pub file:    String,
     pub line:    usize,
     pub column:  usize,
-    pub code:    super::codes::ErrorCode,
     pub message: String,
 }
+
+/// Represents a TypeScript error from the compiler
+#[derive(Debug, Clone)]
+pub struct TsError {
+    /// Empty for global diagnostics that are not tied to a file, see [`TsError::is_global`]
+    pub file:     String,
+    /// 1-indexed, 0 for global diagnostics
+    pub line:     usize,
+    /// 1-indexed, 0 for global diagnostics
+    pub column:   usize,
+    pub code:     super::codes::ErrorCode,
+    pub category: Category,
+    /// Effective severity, from the category and the code unless overridden
+    pub severity: Severity,
+    /// First line of the message, elaborations are kept in `chain`
+    pub message:  String,
+    pub chain:    Vec<MessageChain>,
+    pub related:  Vec<RelatedInformation>,
+}
+
+impl TsError {
+    /// Create a global diagnostic without a location, e.g. `error TS5023: Unknown compiler
+    /// option 'foo'.`
+    pub fn global(code: super::codes::ErrorCode, category: Category, message: String) -> Self {
+        Self {
+            file: String::new(),
+            line: 0,
+            column: 0,
+            code,
+            category,
+            severity: Severity::of(code, category),
+            message,
+            chain: Vec::new(),
+            related: Vec::new(),
+        }
+    }
+
+    /// Create a located diagnostic, splitting indented elaborations off `message`
+    pub fn new(
+        file: String, line: usize, column: usize, code: super::codes::ErrorCode,
+        category: Category, message: &str,
+    ) -> Self {
+        let mut lines = message.lines();
+        let mut err = Self {
+            file,
+            line,
+            column,
+            code,
+            category,
+            severity: Severity::of(code, category),
+            message: lines.next().unwrap_or_default().to_string(),
+            chain: Vec::new(),
+            related: Vec::new(),
+        };
+
+        for line in lines {
+            err.elaborate(line);
+        }
+        err
+    }
+
+    /// Add an indented continuation line to the message chain. tsc indents every level of the
+    /// chain by two more spaces. Lines with their own `file(line,col): ` location are related
+    /// information instead.
+    pub fn elaborate(&mut self, line: &str) {
+        let message = line.trim_start();
+        if message.is_empty() {
+            return;
+        }
+
+        if let Some(related) = super::parse_related(message.trim_end()) {
+            self.related.push(related);
+            return;
+        }
+
+        let depth = ((line.len() - message.len()) / 2).max(1);
+
+        let mut level = 1;
+        let mut siblings = &mut self.chain;
+        // Attach to the last elaboration one level up, tsc never skips a level
+        while level < depth && !siblings.is_empty() {
+            let parent = siblings.len() - 1;
+            siblings = &mut siblings[parent].next;
+            level += 1;
+        }
+
+        siblings.push(MessageChain {
+            message: message.trim_end().to_string(),
+            depth:   level,
+            next:    Vec::new(),
+        });
+    }
+
+    /// The message including every elaboration, one per line
+    pub fn full_message(&self) -> String {
+        let mut out = self.message.clone();
+        for elaboration in self.elaborations() {
+            out.push('\n');
+            out.push_str(&elaboration.message);
+        }
+        out
+    }
+
+    /// The most specific elaboration, the first one at the deepest level of the chain. Falls back
+    /// to the message itself when there are no elaborations.
+    pub fn deepest_message(&self) -> &str {
+        self.elaborations()
+            .fold(None::<&MessageChain>, |deepest, chain| match deepest {
+                Some(deepest) if deepest.depth >= chain.depth => Some(deepest),
+                _ => Some(chain),
+            })
+            .map(|chain| chain.message.as_str())
+            .unwrap_or(&self.message)
+    }
+
+    /// Iterate over all elaborations depth-first, in the order tsc printed them
+    pub fn elaborations(&self) -> impl Iterator<Item = &MessageChain> {
+        let mut stack: Vec<&MessageChain> = self.chain.iter().rev().collect();
+
+        std::iter::from_fn(move || {
+            let chain = stack.pop()?;
+            stack.extend(chain.next.iter().rev());
+            Some(chain)
+        })
+    }
+
+    /// Whether the diagnostic is a config or global diagnostic without a location
+    pub fn is_global(&self) -> bool {
+        self.file.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::codes::ErrorCode;
+
+    fn chained() -> TsError {
+        TsError::new(
+            "a.ts".to_string(),
+            1,
+            1,
+            ErrorCode::TypeMismatch,
+            Category::Error,
+            "Type '{ a: string; }' is not assignable to type 'Foo'.
+  Types of property 'a' are incompatible.
+    Type 'string' is not assignable to type 'number'.
+  Object literal may only specify known properties.",
+        )
+    }
+
+    #[test]
+    fn test_message_chain_depth() {
+        let err = chained();
+
+        assert_eq!(
+            err.message,
+            "Type '{ a: string; }' is not assignable to type 'Foo'."
+        );
+        assert_eq!(err.chain.len(), 2);
+        assert_eq!(err.chain[0].depth, 1);
+        assert_eq!(err.chain[0].next[0].depth, 2);
+        assert_eq!(
+            err.chain[0].next[0].message,
+            "Type 'string' is not assignable to type 'number'."
+        );
+    }
+
+    #[test]
+    fn test_related_information() {
+        let mut err = chained();
+        err.elaborate("  src/(app)/foo.ts(2,3): 'b' is declared here.");
+
+        assert_eq!(err.chain.len(), 2);
+        assert_eq!(
+            err.related,
+            vec![RelatedInformation {
+                file:    "src/(app)/foo.ts".to_string(),
+                line:    2,
+                column:  3,
+                message: "'b' is declared here.".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_quoted_call_stays_in_chain() {
+        let mut err = chained();
+        err.elaborate("    Overload f(1,2): void is not compatible.");
+        err.elaborate("  f(1,2): No overload expects 2 arguments.");
+        err.elaborate("  console.log(1,2): No overload expects 2 arguments.");
+
+        assert!(err.related.is_empty());
+        assert_eq!(err.chain.len(), 4);
+        assert_eq!(
+            err.chain[1].next[0].message,
+            "Overload f(1,2): void is not compatible."
+        );
+        assert_eq!(
+            err.chain[2].message,
+            "f(1,2): No overload expects 2 arguments."
+        );
+    }
+
+    #[test]
+    fn test_deepest_message() {
+        assert_eq!(
+            chained().deepest_message(),
+            "Type 'string' is not assignable to type 'number'."
+        );
+    }
+
+    #[test]
+    fn test_full_message_keeps_order() {
+        assert_eq!(
+            chained().full_message(),
+            "Type '{ a: string; }' is not assignable to type 'Foo'.
+Types of property 'a' are incompatible.
+Type 'string' is not assignable to type 'number'.
+Object literal may only specify known properties."
+        );
+    }
+}
@@ -2,22 +2,212 @@ pub mod codes;
 pub mod core;
 pub mod diagnostics;
 
-pub use core::TsError;
+pub use core::{
+    Category,
+    RelatedInformation,
+    Severity,
+    TsError,
+};
 
 use super::ErrorCode;
 
-/// Parse a TSC error line
+/// Parse a TSC diagnostic line like `src/(auth)/page.tsx(3,7): error TS2322: message`.
+///
+/// The location is anchored on the trailing `(line,col): category TSxxxx:` pattern rather than the
+/// first parenthesis, so paths containing parentheses (Next.js route groups) or Windows drive
+/// letters are kept intact. Lines without a location, like `error TS5023: message`, are parsed
+/// as global diagnostics.
 pub fn parse(line: &str) -> Option<TsError> {
-    let (file, rest) = line.split_once('(')?;
-    let (coords, rest) = rest.split_once("): error ")?;
-    let (line_s, col_s) = coords.split_once(',')?;
-    let (code, msg) = rest.split_once(": ")?;
-
-    Some(TsError {
-        file:    file.to_string(),
-        line:    usize::from_str_radix(line_s, 10).ok()?,
-        column:  usize::from_str_radix(col_s, 10).ok()?,
-        code:    ErrorCode::from_str(code),
-        message: msg.to_string(),
+    for (idx, _) in line.match_indices("): ") {
+        let Some((file, line_no, column)) = parse_location(&line[..idx]) else {
+            continue;
+        };
+        let Some((category, code, message)) = parse_header(&line[idx + 3..]) else {
+            continue;
+        };
+
+        return Some(TsError::new(
+            file.to_string(),
+            line_no,
+            column,
+            code,
+            category,
+            message,
+        ));
+    }
+
+    // Config and global diagnostics have no location, e.g. `error TS18003: No inputs were found`
+    let (category, code, message) = parse_header(line)?;
+    Some(TsError::global(code, category, message.to_string()))
+}
+
+/// Parse a related information line like `src/foo.ts(2,3): 'foo' is declared here.`
+///
+/// The location has to name a file, so elaborations quoting a call like `f(1,2): ` stay part of
+/// the message chain.
+pub(crate) fn parse_related(line: &str) -> Option<RelatedInformation> {
+    line.match_indices("): ").find_map(|(idx, _)| {
+        let (file, line_no, column) = parse_location(&line[..idx])?;
+        if !is_path(file) {
+            return None;
+        }
+
+        Some(RelatedInformation {
+            file: file.to_string(),
+            line: line_no,
+            column,
+            message: line[idx + 3..].to_string(),
+        })
     })
 }
+
+/// Parse `file(line,col` into its parts, the closing parenthesis is already stripped
+pub(crate) fn parse_location(location: &str) -> Option<(&str, usize, usize)> {
+    let (file, coords) = location.rsplit_once('(')?;
+    let (line_s, col_s) = coords.split_once(',')?;
+
+    if file.is_empty() {
+        return None;
+    }
+
+    Some((file, line_s.parse().ok()?, col_s.parse().ok()?))
+}
+
+/// Extensions of the files tsc reports locations in
+const SOURCE_EXTENSIONS: [&str; 9] = ["ts", "tsx", "mts", "cts", "js", "jsx", "mjs", "cjs", "json"];
+
+/// Whether a location names a file: one that exists, or a path with a directory or a source
+/// extension
+fn is_path(file: &str) -> bool {
+    if std::path::Path::new(file).is_file() {
+        return true;
+    }
+    if file.contains(char::is_whitespace) {
+        return false;
+    }
+
+    file.contains(['/', '\\'])
+        || file
+            .rsplit_once('.')
+            .is_some_and(|(stem, ext)| !stem.is_empty() && SOURCE_EXTENSIONS.contains(&ext))
+}
+
+/// Parse `category TSxxxx: message`
+fn parse_header(header: &str) -> Option<(Category, ErrorCode, &str)> {
+    let (category, rest) = header.split_once(' ')?;
+    let category = Category::from_keyword(category)?;
+    let (code, message) = rest.split_once(": ")?;
+
+    let digits = code.strip_prefix("TS")?;
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    Some((category, ErrorCode::from_str(code), message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_located_error() {
+        let err = parse(
+            "src/index.ts(3,7): error TS2322: Type 'string' is not assignable to type 'number'.",
+        )
+        .unwrap();
+
+        assert_eq!(err.file, "src/index.ts");
+        assert_eq!((err.line, err.column), (3, 7));
+        assert_eq!(err.code, ErrorCode::TypeMismatch);
+        assert_eq!(err.category, Category::Error);
+        assert!(!err.is_global());
+    }
+
+    #[test]
+    fn test_parse_odd_paths() {
+        let corpus = [
+            (
+                "src/(auth)/page.tsx(3,7): error TS2304: Cannot find name 'x'.",
+                "src/(auth)/page.tsx",
+            ),
+            (
+                "app/(marketing)/[slug]/page.tsx(1,1): error TS2304: m",
+                "app/(marketing)/[slug]/page.tsx",
+            ),
+            (r"C:\repo\a.ts(12,5): error TS2304: m", r"C:\repo\a.ts"),
+            (
+                r"C:\repo (copy)\src\a.ts(12,5): error TS2304: m",
+                r"C:\repo (copy)\src\a.ts",
+            ),
+            (
+                "my project/src/a b.ts(2,3): error TS2304: m",
+                "my project/src/a b.ts",
+            ),
+            ("src/fn(1,2).ts(4,4): error TS2304: m", "src/fn(1,2).ts"),
+        ];
+
+        for (line, file) in corpus {
+            let err = parse(line).unwrap_or_else(|| panic!("failed to parse {line}"));
+            assert_eq!(err.file, file);
+        }
+    }
+
+    #[test]
+    fn test_parse_message_containing_location() {
+        let err =
+            parse("a.ts(1,2): error TS2322: Type 'f(1,2): error' is not assignable.").unwrap();
+
+        assert_eq!(err.file, "a.ts");
+        assert_eq!(err.message, "Type 'f(1,2): error' is not assignable.");
+    }
+
+    #[test]
+    fn test_parse_categories() {
+        let warning = parse("a.ts(1,2): warning TS6133: 'x' is declared but never used.").unwrap();
+        let message = parse("a.ts(1,2): message TS6133: 'x' is declared but never used.").unwrap();
+
+        assert_eq!(warning.category, Category::Warning);
+        assert_eq!(message.category, Category::Message);
+    }
+
+    #[test]
+    fn test_severity_defaults() {
+        let unused = parse("a.ts(1,7): error TS6133: 'x' is declared but its value is never read.");
+        let unreachable = parse("a.ts(2,1): error TS95050: Unreachable code detected.");
+        let mismatch = parse("a.ts(1,1): warning TS2322: Type 'string' is not assignable.");
+
+        assert_eq!(unused.unwrap().severity, Severity::Warning);
+        assert_eq!(unreachable.unwrap().severity, Severity::Advice);
+        assert_eq!(mismatch.unwrap().severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_parse_global_error() {
+        let err = parse("error TS5023: Unknown compiler option 'foo'.").unwrap();
+
+        assert!(err.is_global());
+        assert_eq!(err.code, ErrorCode::UnknownCompilerOption(5023));
+        assert_eq!(err.message, "Unknown compiler option 'foo'.");
+    }
+
+    #[test]
+    fn test_folded_codes_keep_their_number() {
+        let err = parse("error TS5025: Unknown compiler option 'stric'. Did you mean 'strict'?");
+        let err = err.unwrap();
+
+        assert_eq!(err.code, ErrorCode::UnknownCompilerOption(5025));
+        assert_eq!(err.code.to_string(), "TS5025");
+        assert_eq!(ErrorCode::from_str("TS7044").to_string(), "TS7044");
+        assert_eq!(ErrorCode::from_str("TS7006").to_string(), "TS7006");
+    }
+
+    #[test]
+    fn test_parse_ignores_non_diagnostics() {
+        assert!(parse("Files:                         1").is_none());
+        assert!(parse("error while loading: something").is_none());
+        assert!(parse("a.ts(1,2): info TS2304: m").is_none());
+        assert!(parse("a.ts(x,2): error TS2304: m").is_none());
+        assert!(parse("  Type 'string' is not assignable to type 'number'.").is_none());
+    }
+}
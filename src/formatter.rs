@@ -1,128 +1,608 @@
+use std::ops::Range;
+
 use ariadne::{
+    CharSet,
     Color,
+    Config,
+    IndexType,
     Label,
     Report,
     ReportKind,
-    Source,
 };
 use colored::*;
 
 use crate::{
-    diagnostics::suggestions::ErrorDiagnostic,
-    error::TsError,
-    tokenizer::Tokenizer,
+    diagnostics::{
+        Diagnostic,
+        styled::StyledText,
+    },
+    error::{
+        RelatedInformation,
+        Severity,
+        TsError,
+    },
 };
 
-/// Pretty format
-pub fn fmt(err: &TsError) -> String {
-    let src = std::fs::read_to_string(&err.file).unwrap_or_default();
-    if src.is_empty() {
-        return fmt_simple(err);
-    }
+/// Characters code frames are drawn with
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Charset {
+    /// Box drawing characters
+    #[default]
+    Unicode,
+    /// ASCII only, for terminals and logs that mangle box drawing characters
+    Ascii,
+}
 
-    let tokens = Tokenizer::new(src.clone()).tokenize();
-    let mut span = None;
+/// How code frames are drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// Lines of source shown above and below the reported lines
+    pub context_lines: usize,
+    pub charset:       Charset,
+    /// Leave out the blank lines and padding around labels
+    pub compact:       bool,
+    /// Columns a tab in the source is expanded to
+    pub tab_width:     usize,
+    /// Wrap messages, notes and help to this many columns, `None` never wraps. Label messages
+    /// next to the code are not wrapped.
+    pub wrap_width:    Option<usize>,
+}
 
-    for token in &tokens {
-        if token.line == err.line
-            && (err.column - 1) >= token.column
-            && (err.column - 1) < token.column + token.raw.chars().count()
-        {
-            span = Some(token.start..token.end);
-            break;
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            context_lines: 0,
+            charset:       Charset::Unicode,
+            compact:       false,
+            tab_width:     4,
+            wrap_width:    None,
         }
     }
+}
+
+/// Narrowest a wrapped message gets, however deep it is indented
+const MIN_WRAP_WIDTH: usize = 20;
 
-    // If no token matched, calculate span from line/column
-    let span = span.unwrap_or_else(|| {
-        let mut byte_offset = 0;
-        let mut current_line = 1;
-        let mut current_column = 0;
+/// Pretty format an already enriched diagnostic
+pub fn render(diagnostic: &Diagnostic) -> String {
+    render_with(diagnostic, &RenderOptions::default())
+}
 
-        for ch in src.chars() {
-            if current_line == err.line && current_column == err.column - 1 {
-                // Found the position, use a small span for the character
-                let char_len = ch.len_utf8();
-                return byte_offset..byte_offset + char_len;
-            }
+/// Pretty format an already enriched diagnostic with the given code frame options
+pub fn render_with(diagnostic: &Diagnostic, options: &RenderOptions) -> String {
+    let err = &diagnostic.error;
+    if err.is_global() {
+        return fmt_global(diagnostic, options);
+    }
+    if diagnostic.source.is_empty() {
+        return fmt_simple(err);
+    }
 
-            if ch == '\n' {
-                current_line += 1;
-                current_column = 0;
-            } else {
-                current_column += 1;
-            }
+    let out = write_report(diagnostic, options, None);
 
-            byte_offset += ch.len_utf8();
+    // Help and notes are wrapped to fit after the column ariadne prints them at, which depends on
+    // the margin and labels of the frame. It is measured on the report rendered without wrapping.
+    match options.wrap_width.zip(help_column(&out)) {
+        Some((width, column)) => {
+            write_report(diagnostic, options, Some(width.saturating_sub(column)))
         }
+        None => out,
+    }
+}
 
-        byte_offset.max(1) - 1..byte_offset
-    });
-
-    let suggestion = err.code.suggest(err, &tokens);
+/// Ariadne report of a diagnostic with source, help and notes are wrapped to `help_width` columns
+/// after their `Help:` or `Note:` prefix when given
+fn write_report(
+    diagnostic: &Diagnostic, options: &RenderOptions, help_width: Option<usize>,
+) -> String {
+    let err = &diagnostic.error;
+    let span = diagnostic.span.clone();
+    let suggestion = &diagnostic.suggestion;
 
     let mut buf = Vec::new();
 
-    // determine the span, either from tokens or the default
-    let label_span = suggestion
-        .as_ref()
-        .and_then(|s| s.span.clone())
-        .unwrap_or_else(|| span.clone());
+    // determine the span, either from the suggestion or the default
+    let label_span = diagnostic.label_span();
+    let context = Context::new(diagnostic, &label_span, options.context_lines);
+
+    // Follow the color choice of `colored` so --color and NO_COLOR apply to both. Spans are byte
+    // offsets, so lines with multibyte characters are marked correctly.
+    let config = Config::default()
+        .with_color(control::SHOULD_COLORIZE.should_colorize())
+        .with_char_set(match options.charset {
+            Charset::Unicode => CharSet::Unicode,
+            Charset::Ascii => CharSet::Ascii,
+        })
+        .with_compact(options.compact)
+        .with_tab_width(options.tab_width)
+        .with_index_type(IndexType::Byte);
 
-    let mut report = Report::build(ReportKind::Error, (&err.file, span.clone()))
+    let (kind, color) = report_kind(err.severity);
+    let header = format!("[{}] {}: ", err.code, kind).len();
+    let mut report = Report::build(kind, (err.file.clone(), span))
+        .with_config(config)
         .with_code(err.code)
-        .with_message(&err.message);
-
-    if let Some(ref s) = suggestion {
-        if !s.suggestions.is_empty() {
-            for suggestion_text in s.suggestions.iter() {
-                report = report.with_label(
-                    Label::new((&err.file, label_span.clone()))
-                        .with_color(Color::Red)
-                        .with_message(suggestion_text),
-                );
-            }
+        .with_message(chained_message(err, options.wrap_width, header));
+
+    let message = suggestion
+        .as_ref()
+        .and_then(|s| s.label.as_ref())
+        .map(StyledText::ansi)
+        .unwrap_or_else(|| format!("{} found here ", kind));
+    // Labels with the offset they start at
+    let mut labels = vec![(
+        label_span.start,
+        Label::new((err.file.clone(), label_span.clone()))
+            .with_color(color)
+            .with_message(message),
+    )];
+
+    for secondary in suggestion.iter().flat_map(|s| &s.secondary) {
+        labels.push((
+            secondary.span.start,
+            Label::new((err.file.clone(), secondary.span.clone()))
+                .with_color(Color::Yellow)
+                .with_message(secondary.message.ansi()),
+        ));
+    }
+
+    // Ariadne only shows lines that have a label, so context lines get one without a message on
+    // their first character. An empty label at a line start would count for the line above.
+    for offset in context.before.iter().chain(&context.after) {
+        labels.push((
+            *offset,
+            Label::new((err.file.clone(), *offset..*offset + 1)).with_color(Color::Fixed(249)),
+        ));
+    }
+
+    // Related locations become secondary labels, the ones in other files get frames of their own
+    let mut other_files = Vec::new();
+    for related in &diagnostic.related {
+        let label = Label::new((related.info.file.clone(), related.span.clone()))
+            .with_color(Color::Blue)
+            .with_message(&related.info.message);
+        if related.info.file == err.file {
+            labels.push((related.span.start, label));
         } else {
-            report = report.with_label(
-                Label::new((&err.file, label_span.clone()))
-                    .with_color(Color::Red)
-                    .with_message("Error found here ".to_string()),
-            );
+            other_files.push((related.span.start, label));
         }
-    } else {
-        report = report.with_label(
-            Label::new((&err.file, label_span))
-                .with_color(Color::Red)
-                .with_message("Error found here ".to_string()),
-        );
     }
 
+    // Labels of a file have to be added top to bottom, ariadne starts a new frame otherwise
+    labels.sort_by_key(|(start, _)| *start);
+    other_files.sort_by_key(|(start, _)| *start);
+    report = report.with_labels(
+        labels
+            .into_iter()
+            .chain(other_files)
+            .map(|(_, label)| label),
+    );
+
+    // Notes of the suggestion, then related locations in files that could not be read, which can
+    // only be mentioned
+    let notes: Vec<String> = suggestion
+        .iter()
+        .flat_map(|s| &s.notes)
+        .map(StyledText::ansi)
+        .chain(unresolved_related(diagnostic).map(|info| {
+            format!(
+                "{} ({}:{}:{})",
+                info.message, info.file, info.line, info.column
+            )
+        }))
+        .collect();
+
+    // Ariadne numbers the notes when there are several, continuation lines line up after the
+    // prefix
+    let wrapped = |text: &str, prefix: &str| match help_width {
+        Some(width) => {
+            let width = width.saturating_sub(prefix.len() + 2);
+            wrap(text, width, width, "")
+        }
+        None => text.to_string(),
+    };
+    for (idx, note) in notes.iter().enumerate() {
+        let prefix = if notes.len() > 1 {
+            format!("Note {}", idx + 1)
+        } else {
+            "Note".to_string()
+        };
+        report = report.with_note(wrapped(note, &prefix));
+    }
+
+    let sources = std::iter::once((err.file.clone(), diagnostic.source.as_str())).chain(
+        diagnostic
+            .sources
+            .iter()
+            .map(|(file, source)| (file.clone(), source.as_str())),
+    );
+
+    let help = suggestion
+        .as_ref()
+        .and_then(|s| s.help.as_ref())
+        .map(StyledText::ansi)
+        .unwrap_or_else(|| "No suggestion available.".to_string());
+
     report
-        .with_help(
-            suggestion
-                .as_ref()
-                .and_then(|s| s.help.clone())
-                .unwrap_or_else(|| "No suggestion available.".to_string()),
-        )
+        .with_help(wrapped(&help, "Help"))
         .finish()
-        .write((&err.file, Source::from(src)), &mut buf)
+        .write(ariadne::sources(sources), &mut buf)
         .ok();
 
     String::from_utf8(buf).unwrap_or_else(|_| fmt_simple(err))
 }
 
+/// Column the `Help:` line of a rendered report starts at, notes start at the same column
+fn help_column(report: &str) -> Option<usize> {
+    report.lines().rev().find_map(|line| {
+        let line = strip_ansi(line);
+        let (prefix, _) = line.split_once("Help: ")?;
+        prefix
+            .chars()
+            .all(|c| matches!(c, ' ' | '│' | '|'))
+            .then(|| prefix.chars().count())
+    })
+}
+
+/// Kind of the report for a severity, with the color of its primary label
+fn report_kind(severity: Severity) -> (ReportKind<'static>, Color) {
+    match severity {
+        Severity::Error => (ReportKind::Error, Color::Red),
+        Severity::Warning => (ReportKind::Warning, Color::Yellow),
+        Severity::Advice => (ReportKind::Advice, Color::Fixed(147)),
+    }
+}
+
+/// Text in the color of a severity, matching the report kinds ariadne draws
+fn paint(text: &str, severity: Severity) -> ColoredString {
+    match severity {
+        Severity::Error => text.red(),
+        Severity::Warning => text.yellow(),
+        Severity::Advice => text.ansi_color(147),
+    }
+}
+
+/// Start of every context line shown around the reported lines
+struct Context {
+    before: Vec<usize>,
+    after:  Vec<usize>,
+}
+
+impl Context {
+    fn new(diagnostic: &Diagnostic, label_span: &Range<usize>, lines: usize) -> Self {
+        let source = diagnostic.source.as_str();
+        let starts = line_starts(source);
+        let line_of = |offset: usize| starts.partition_point(|start| *start <= offset) - 1;
+
+        let first = line_of(label_span.start.min(diagnostic.span.start));
+        let last = line_of(
+            label_span
+                .end
+                .max(diagnostic.span.end)
+                .saturating_sub(1)
+                .max(first),
+        );
+
+        Self {
+            before: starts[first.saturating_sub(lines)..first].to_vec(),
+            after:  starts
+                .iter()
+                .copied()
+                .skip(last + 1)
+                .take(lines)
+                .filter(|start| *start < source.len())
+                .collect(),
+        }
+    }
+}
+
+/// Byte offset of the start of every line
+fn line_starts(source: &str) -> Vec<usize> {
+    std::iter::once(0)
+        .chain(source.match_indices('\n').map(|(idx, _)| idx + 1))
+        .collect()
+}
+
+/// Greedy word wrap, ANSI escapes take up no space. The first line has `first` columns, the
+/// continuation lines start with `indent` and have `rest` columns after it.
+fn wrap(text: &str, first: usize, rest: usize, indent: &str) -> String {
+    let mut out = String::new();
+    let mut width = first.max(MIN_WRAP_WIDTH);
+    let mut line = 0;
+
+    for word in text.split(' ') {
+        let len = visible_len(word);
+        if line > 0 && line + 1 + len > width {
+            out.push('\n');
+            out.push_str(indent);
+            width = rest.max(MIN_WRAP_WIDTH);
+            line = 0;
+        } else if !out.is_empty() {
+            out.push(' ');
+            line += 1;
+        }
+        out.push_str(word);
+        line += len;
+    }
+
+    out
+}
+
+fn visible_len(text: &str) -> usize {
+    strip_ansi(text).chars().count()
+}
+
+fn strip_ansi(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            // Skip the escape sequence up to its final letter
+            chars.by_ref().find(|c| c.is_ascii_alphabetic());
+        } else {
+            out.push(c);
+        }
+    }
+
+    out
+}
+
+/// The message followed by its elaborations, indented as a hierarchy by depth. Lines are wrapped
+/// to `wrap_width` when given, the first line starts `offset` columns in.
+fn chained_message(err: &TsError, wrap_width: Option<usize>, offset: usize) -> String {
+    let wrapped = |message: &str, indent: usize| match wrap_width {
+        Some(width) => {
+            let width = width.saturating_sub(indent);
+            wrap(message, width, width, &" ".repeat(indent))
+        }
+        None => message.to_string(),
+    };
+    let mut out = match wrap_width {
+        Some(width) => wrap(&err.message, width.saturating_sub(offset), width, ""),
+        None => err.message.clone(),
+    };
+
+    for elaboration in err.elaborations() {
+        let indent = 3 * elaboration.depth;
+        out.push('\n');
+        out.push_str(&" ".repeat(indent));
+        out.push_str(&format!(
+            "{} {}",
+            "└─".bright_black(),
+            wrapped(&elaboration.message, indent + 3)
+        ));
+    }
+
+    out
+}
+
+/// Related locations that could not be resolved against their source
+fn unresolved_related(diagnostic: &Diagnostic) -> impl Iterator<Item = &RelatedInformation> {
+    diagnostic
+        .error
+        .related
+        .iter()
+        .filter(|info| !diagnostic.related.iter().any(|r| r.info == **info))
+}
+
+/// Related locations as `--> file:line:col message` lines
+fn related_lines(err: &TsError) -> String {
+    err.related
+        .iter()
+        .map(|info| {
+            format!(
+                "  --> {}:{}:{} {}\n",
+                info.file.cyan(),
+                info.line.to_string().cyan(),
+                info.column.to_string().cyan(),
+                info.message
+            )
+        })
+        .collect()
+}
+
 /// Simple formatting without src extraction
 fn fmt_simple(err: &TsError) -> String {
-    format!(
+    let mut out = format!(
         "{}:{}:{} - {} {}: {}\n  --> {}:{}:{}\n      |\n      = TypeScript compiler error\n",
         err.file.cyan(),
         err.line.to_string().yellow(),
         err.column.to_string().yellow(),
-        "error".red().bold(),
-        err.code.to_string().red().bold(),
-        err.message,
+        paint(err.severity.as_str(), err.severity).bold(),
+        paint(&err.code.to_string(), err.severity).bold(),
+        chained_message(err, None, 0),
         err.file.cyan(),
         err.line.to_string().cyan(),
         err.column.to_string().cyan()
-    )
+    );
+
+    out.push_str(&related_lines(err));
+    out
+}
+
+/// Format a global diagnostic that has no source location to point at
+fn fmt_global(diagnostic: &Diagnostic, options: &RenderOptions) -> String {
+    let err = &diagnostic.error;
+    let ascii = options.charset == Charset::Ascii;
+    let margin = if ascii { "|" } else { "│" }.bright_black();
+    let header = format!("[{}] {}:", err.code, report_kind(err.severity).0);
+    let mut out = format!(
+        "{} {}\n",
+        paint(&header, err.severity),
+        chained_message(err, options.wrap_width, header.len() + 1)
+    );
+
+    // Continuation lines of wrapped text line up with the first line
+    let wrapped = |text: String, offset: usize| match options.wrap_width {
+        Some(width) => {
+            let indent = format!("   {}{}", margin, " ".repeat(offset - 4));
+            let width = width.saturating_sub(offset);
+            wrap(&text, width, width, &indent)
+        }
+        None => text,
+    };
+
+    if let Some(ref s) = diagnostic.suggestion {
+        for suggestion_text in s.messages() {
+            out.push_str(&format!(
+                "   {} {}\n",
+                margin,
+                wrapped(suggestion_text.ansi(), 5)
+            ));
+        }
+    }
+
+    out.push_str(&related_lines(err));
+
+    let help = diagnostic
+        .suggestion
+        .as_ref()
+        .and_then(|s| s.help.as_ref())
+        .map(StyledText::ansi)
+        .unwrap_or_else(|| "No suggestion available.".to_string());
+
+    out.push_str(&format!(
+        "   {}\n   {} {}: {}\n{}\n",
+        margin,
+        margin,
+        "Help".bright_cyan(),
+        wrapped(help, 11),
+        if ascii { "---'" } else { "───╯" }.bright_black()
+    ));
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        diagnostics::{
+            styled::Segment,
+            suggestions::{
+                SecondaryLabel,
+                Suggestion,
+            },
+        },
+        error::parse,
+    };
+
+    /// Numbers of the source lines shown in the code frame
+    fn frame_lines(out: &str) -> Vec<usize> {
+        out.lines()
+            .filter_map(|line| line.split_once(" │ ")?.0.trim().parse().ok())
+            .collect()
+    }
+
+    #[test]
+    fn test_context_lines() {
+        colored::control::set_override(false);
+        let err = parse("a.ts(3,11): error TS2304: Cannot find name 'foo'.").unwrap();
+        let source = "one;\ntwo;\nconst a = foo;\nfour;\nfive;\nsix;\nseven;\n";
+        let diagnostic = Diagnostic::with_source(err, source.to_string());
+        let render = |context_lines| {
+            render_with(
+                &diagnostic,
+                &RenderOptions {
+                    context_lines,
+                    ..Default::default()
+                },
+            )
+        };
+
+        assert_eq!(frame_lines(&render(0)), vec![3]);
+        assert_eq!(frame_lines(&render(1)), vec![2, 3, 4]);
+        assert_eq!(frame_lines(&render(3)), vec![1, 2, 3, 4, 5, 6]);
+        // The underline ends with the span, not with the line
+        assert!(render(1).contains(&format!("   │{}─┬─  \n", " ".repeat(11))));
+    }
+
+    #[test]
+    fn test_wrap_help_after_measured_column() {
+        colored::control::set_override(false);
+        let err = parse("a.ts(2,17): error TS2304: Cannot find name 'foo'.").unwrap();
+        let source = "one;\nconst answer = foo;\n";
+        let out = render_with(
+            &Diagnostic::with_source(err, source.to_string()),
+            &RenderOptions {
+                wrap_width: Some(40),
+                ..Default::default()
+            },
+        );
+
+        // The label next to the code is left as is, everything after it fits
+        assert!(out.contains("╰─── Identifier `foo` can not be found in the current scope."));
+        let help: Vec<&str> = out
+            .lines()
+            .skip_while(|line| !line.contains("Help:"))
+            .collect();
+        assert!(help.len() > 2);
+        assert!(help.iter().all(|line| line.chars().count() <= 40));
+        assert!(help[1].starts_with("   │       "));
+    }
+
+    #[test]
+    fn test_suggestion_parts() {
+        colored::control::set_override(false);
+        let err = parse("a.ts(2,10): error TS2393: Duplicate function implementation.").unwrap();
+        let source = "function f() {}\nfunction f() {}\n";
+        let text = |text: &str| StyledText {
+            segments: vec![Segment::plain(text)],
+        };
+        let mut diagnostic = Diagnostic::with_source(err, source.to_string());
+        diagnostic.suggestion = Some(Suggestion {
+            label:     Some(text("primary label")),
+            notes:     vec![text("first note"), text("second note")],
+            secondary: vec![SecondaryLabel {
+                span:    9..10,
+                message: text("secondary label"),
+            }],
+            help:      Some(text("the help")),
+            span:      None,
+        });
+        let out = render(&diagnostic);
+
+        let position = |text: &str| out.find(text).unwrap_or_else(|| panic!("{text}:\n{out}"));
+        let order = [
+            " 1 │ function f() {}",
+            "╰── secondary label",
+            " 2 │ function f() {}",
+            "╰── primary label",
+            "│ Help: the help",
+            "│ Note 1: first note",
+            "│ Note 2: second note",
+        ]
+        .map(position);
+
+        assert!(order.is_sorted(), "{out}");
+    }
+
+    #[test]
+    fn test_related_label_above_error_in_same_frame() {
+        colored::control::set_override(false);
+        let mut err = parse("r.ts(3,18): error TS2304: Cannot find name 'foo'.").unwrap();
+        err.related.push(RelatedInformation {
+            file:    "r.ts".to_string(),
+            line:    1,
+            column:  17,
+            message: "'foo' is declared here.".to_string(),
+        });
+        let source = "declare function foo(): void;\n\nconst value = 1 + foo;\n";
+        let out = render(&Diagnostic::with_source(err, source.to_string()));
+
+        assert_eq!(out.matches("─[ r.ts:").count(), 1, "{out}");
+        assert_eq!(frame_lines(&out), vec![1, 3]);
+        assert!(out.contains("'foo' is declared here."), "{out}");
+    }
+
+    #[test]
+    fn test_wrap_skips_ansi_escapes() {
+        let text = "Try converting \u{1b}[1;31m`x`\u{1b}[0m to a number before the call";
+
+        assert_eq!(
+            wrap(text, 21, 27, "  | "),
+            "Try converting \u{1b}[1;31m`x`\u{1b}[0m to\n  | a number before the call"
+        );
+    }
 }
@@ -0,0 +1,111 @@
+//! Prettify and enrich diagnostics emitted by `tsc`.
+//!
+//! The public API follows the same three steps the `ts-analyzer` binary takes:
+//!
+//! 1. [`parse_output`] turns raw `tsc --pretty false` output into [`TsError`]s.
+//! 2. [`enrich`] attaches the source span and [`Suggestion`] for a single error.
+//! 3. [`render`] formats an enriched [`Diagnostic`] as an ariadne report, [`render_with`] takes
+//!    [`RenderOptions`] for context lines, box characters and wrapping.
+//!
+//! ```no_run
+//! let output = ts_analyzer::run_tsc(None, &Default::default()).unwrap();
+//!
+//! for err in ts_analyzer::parse_output(&output) {
+//!     println!("{}", ts_analyzer::render(&ts_analyzer::enrich(err)));
+//! }
+//! ```
+
+pub(crate) mod build;
+pub(crate) mod diagnostics;
+pub(crate) mod error;
+pub(crate) mod formatter;
+mod message_parser;
+pub(crate) mod output;
+mod token_utils;
+pub(crate) mod tokenizer;
+pub(crate) mod tsc;
+
+pub use build::{
+    ProjectDiagnostics,
+    parse_output as parse_build_output,
+};
+pub use diagnostics::{
+    Diagnostic,
+    suggestions::{
+        ErrorDiagnostic,
+        SecondaryLabel,
+        Suggestion,
+    },
+};
+pub use error::{
+    Category,
+    Severity,
+    TsError,
+    codes::ErrorCode,
+};
+pub use formatter::{
+    Charset,
+    RenderOptions,
+};
+pub use output::{
+    Format,
+    GroupBy,
+    SortBy,
+    fails,
+    render as render_report,
+    sort,
+    terminal::{
+        Options as TerminalOptions,
+        render_with as render_terminal,
+        reports as terminal_reports,
+        totals as terminal_totals,
+    },
+};
+pub use tsc::{
+    Options as TscOptions,
+    build as build_tsc,
+    run as run_tsc,
+};
+
+/// Parse the raw output of `tsc --pretty false` into a list of errors.
+///
+/// Indented lines following an error are elaborations and become its message chain. Any other
+/// line that is not an error (timings, `--diagnostics` output) is skipped.
+pub fn parse_output(buf: &str) -> Vec<TsError> {
+    let mut errors = Vec::new();
+    let lines: Vec<&str> = buf.lines().collect();
+    let mut i = 0;
+
+    while i < lines.len() {
+        if let Some(mut parsed) = error::parse(lines[i]) {
+            // Collect continuation lines (indented lines following the error)
+            let mut indented_line = i + 1;
+            while indented_line < lines.len() && lines[indented_line].starts_with("  ") {
+                parsed.elaborate(lines[indented_line]);
+                indented_line += 1;
+            }
+
+            errors.push(parsed);
+            i = indented_line;
+        } else {
+            i += 1;
+        }
+    }
+
+    errors
+}
+
+/// Resolve the source span and suggestion for an error
+pub fn enrich(err: TsError) -> Diagnostic {
+    Diagnostic::new(err)
+}
+
+/// Render an enriched diagnostic as a pretty report
+pub fn render(diagnostic: &Diagnostic) -> String {
+    formatter::render(diagnostic)
+}
+
+/// Render an enriched diagnostic as a pretty report with the given code frame options
+pub fn render_with(diagnostic: &Diagnostic, options: &RenderOptions) -> String {
+    formatter::render_with(diagnostic, options)
+}
@@ -1,23 +1,129 @@
-use anyhow::Result;
-use clap::Parser;
-use colored::*;
-
-use crate::error::codes::ErrorCode;
+use std::{
+    fmt::Write,
+    io::IsTerminal,
+    path::PathBuf,
+    process::ExitCode,
+};
 
-mod diagnostics;
-mod error;
-mod formatter;
-mod message_parser;
-mod token_utils;
-mod tokenizer;
+use anyhow::{
+    Context,
+    Result,
+};
+use clap::{
+    Parser,
+    ValueEnum,
+};
+use colored::*;
+use ts_analyzer::{
+    Category,
+    Charset,
+    Diagnostic,
+    ErrorCode,
+    Format,
+    GroupBy,
+    RenderOptions,
+    Severity,
+    SortBy,
+    TerminalOptions,
+    TsError,
+    TscOptions,
+};
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 struct Cli {
-    /// Optional file to read TSC error output from. If not provided, runs `tsc` in the current
-    /// directory.
+    /// Optional file to type check. If not provided, runs `tsc` in the current directory.
     input: Option<String>,
 
+    /// Read pre-captured `tsc --pretty false` output from stdin instead of running tsc
+    #[arg(long, conflicts_with_all = ["input", "from_log", "from_lsp"])]
+    from_stdin: bool,
+
+    /// Read pre-captured `tsc --pretty false` output from a log file instead of running tsc
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["input", "from_lsp"])]
+    from_log: Option<String>,
+
+    /// Path to the tsc executable. Defaults to the nearest `node_modules/.bin/tsc`, then `tsc`
+    /// from PATH
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["from_stdin", "from_log", "from_lsp"])]
+    tsc: Option<PathBuf>,
+
+    /// tsconfig.json to check, forwarded to tsc as `--project`
+    #[arg(
+        long,
+        short,
+        value_name = "TSCONFIG",
+        conflicts_with_all = ["from_stdin", "from_log", "from_lsp"]
+    )]
+    project: Option<PathBuf>,
+
+    /// Extra arguments forwarded to tsc verbatim, e.g. `ts-analyzer -- --skipLibCheck`
+    #[arg(last = true, value_name = "TSC_ARGS")]
+    tsc_args: Vec<String>,
+
+    /// Build the project and its references with `tsc --build` and report diagnostics per
+    /// project. Combined with --from-stdin or --from-log, reads `tsc --build --verbose` output.
+    #[arg(long, short, conflicts_with_all = ["input", "from_lsp"])]
+    build: bool,
+
+    /// Output format of the report
+    #[arg(long, value_enum, default_value_t = FormatArg::Terminal)]
+    format: FormatArg,
+
+    /// Group the terminal report into a section per file or per error code. Not available with
+    /// --build, which already has a section per project.
+    #[arg(long, value_enum, value_name = "GROUP", conflicts_with_all = ["build", "from_lsp"])]
+    group_by: Option<GroupByArg>,
+
+    /// Sort diagnostics in every report instead of keeping the order tsc reported them in
+    #[arg(long, value_enum, value_name = "ORDER")]
+    sort: Option<SortByArg>,
+
+    /// Render every diagnostic in the terminal report. By default diagnostics with the same code
+    /// and message are rendered once, followed by the locations of the others.
+    #[arg(long)]
+    no_collapse: bool,
+
+    /// Lines of source shown above and below each error in terminal reports
+    #[arg(long, value_name = "LINES", default_value_t = 0)]
+    context_lines: usize,
+
+    /// Characters the code frames of terminal reports are drawn with
+    #[arg(long, value_enum, default_value_t = CharsetArg::Unicode)]
+    charset: CharsetArg,
+
+    /// Leave out the blank lines and padding around labels in code frames
+    #[arg(long)]
+    compact_frames: bool,
+
+    /// Columns a tab in the source is expanded to in code frames
+    #[arg(long, value_name = "COLUMNS", default_value_t = 4)]
+    tab_width: usize,
+
+    /// Wrap messages in terminal reports to this many columns, 0 never wraps. Defaults to
+    /// $COLUMNS when stdout is a terminal.
+    #[arg(long, value_name = "COLUMNS")]
+    wrap: Option<usize>,
+
+    /// When to color terminal reports. `auto` colors stdout when it is a terminal and honours
+    /// NO_COLOR, CLICOLOR and CLICOLOR_FORCE, reports written to files are not colored.
+    #[arg(long, value_enum, default_value_t = ColorChoice::Auto)]
+    color: ColorChoice,
+
+    /// Report every diagnostic of a code with this severity, repeatable. TS6133 and TS6192 are
+    /// warnings and TS95050 is advice unless overridden.
+    #[arg(long = "severity", value_name = "CODE=LEVEL", value_parser = parse_severity_arg)]
+    severities: Vec<SeverityArg>,
+
+    /// Least severe diagnostic that fails the run
+    #[arg(long, value_enum, value_name = "LEVEL", default_value_t = Level::Error)]
+    fail_on: Level,
+
+    /// Write a report to a file, repeatable. `<format>=<path>` writes an additional report in
+    /// that format, a plain path writes the --format report instead of printing it.
+    #[arg(long, short, value_name = "[FORMAT=]PATH", value_parser = parse_output_arg)]
+    output: Vec<OutputArg>,
+
     /// Format a diagnostic from LSP instead of running tsc
     #[arg(long)]
     from_lsp: bool,
@@ -43,124 +149,547 @@ struct Cli {
     file: Option<String>,
 }
 
-fn main() -> Result<()> {
+fn main() -> Result<ExitCode> {
     let cli = Cli::parse();
+    let sinks = sinks(cli.format.into(), cli.output, cli.color);
+    let sort = cli.sort.map(Into::into);
+    let options = TerminalOptions {
+        group_by: cli.group_by.map(Into::into),
+        collapse: !cli.no_collapse,
+        render:   RenderOptions {
+            context_lines: cli.context_lines,
+            charset:       cli.charset.into(),
+            compact:       cli.compact_frames,
+            tab_width:     cli.tab_width,
+            wrap_width:    cli.wrap.or_else(terminal_width).filter(|width| *width > 0),
+        },
+    };
 
     if cli.from_lsp {
         // LSP mode: format a single diagnostic
-        format_lsp_diagnostic(
+        let diagnostic = lsp_diagnostic(
             cli.file.expect("--file required"),
             cli.line.expect("--line required"),
             cli.column.expect("--column required"),
             cli.code.expect("--code required"),
             cli.message.expect("--message required"),
-        )?;
+            &cli.severities,
+        );
+
+        for sink in &sinks {
+            sink.set_color();
+            let report = if sink.format == Format::Terminal {
+                format!(
+                    "{}\n",
+                    ts_analyzer::render_with(&diagnostic, &options.render)
+                )
+            } else {
+                ts_analyzer::render_report(sink.format, std::slice::from_ref(&diagnostic))
+            };
+            sink.write(&report)?;
+        }
     } else {
-        // Default behavior: parse tsc output
-        parse_tsc_output(cli.input)?;
+        let buf = if cli.from_stdin {
+            std::io::read_to_string(std::io::stdin())?
+        } else if let Some(log) = cli.from_log {
+            std::fs::read_to_string(&log)
+                .with_context(|| format!("failed to read tsc output from {}", log))?
+        } else {
+            // Default behavior: run tsc and parse its output
+            let options = TscOptions {
+                compiler: cli.tsc,
+                project:  cli.project,
+                args:     cli.tsc_args,
+            };
+
+            if cli.build {
+                ts_analyzer::build_tsc(&options)?
+            } else {
+                ts_analyzer::run_tsc(cli.input.as_deref(), &options)?
+            }
+        };
+
+        let diagnostics = if cli.build {
+            parse_build_output(buf, &sinks, sort, &cli.severities, &options)?
+        } else {
+            parse_tsc_output(buf, &sinks, sort, &cli.severities, &options)?
+        };
+
+        if ts_analyzer::fails(&diagnostics, cli.fail_on.into()) {
+            return Ok(ExitCode::FAILURE);
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Width of the terminal from $COLUMNS, when stdout is one
+fn terminal_width() -> Option<usize> {
+    if !std::io::stdout().is_terminal() {
+        return None;
+    }
+
+    std::env::var("COLUMNS").ok()?.parse().ok()
+}
+
+/// When reports are colored, see `--color`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ColorChoice {
+    Auto,
+    Always,
+    Never,
+}
+
+/// An output format as given to `--format` and `--output`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum FormatArg {
+    /// Pretty ariadne reports with suggestions
+    Terminal,
+    /// Versioned JSON document, see the readme for the schema
+    Json,
+    /// SARIF 2.1.0 log for code scanning dashboards
+    Sarif,
+    /// GitHub Actions workflow commands for inline pull request annotations
+    Github,
+    /// JUnit XML report with a testsuite per file, for test dashboards
+    Junit,
+    /// GitLab Code Quality report
+    Gitlab,
+    /// Checkstyle XML report
+    Checkstyle,
+    /// Reviewdog rdjson with concrete fixes as suggested edits
+    Rdjson,
+    /// Markdown report sized for pull request comments
+    Markdown,
+    /// Self-contained HTML report with highlighted code frames, usually written with --output
+    Html,
+    /// One line per diagnostic for editor quickfix lists and grep
+    Compact,
+}
+
+impl From<FormatArg> for Format {
+    fn from(format: FormatArg) -> Self {
+        match format {
+            FormatArg::Terminal => Format::Terminal,
+            FormatArg::Json => Format::Json,
+            FormatArg::Sarif => Format::Sarif,
+            FormatArg::Github => Format::Github,
+            FormatArg::Junit => Format::Junit,
+            FormatArg::Gitlab => Format::Gitlab,
+            FormatArg::Checkstyle => Format::Checkstyle,
+            FormatArg::Rdjson => Format::Rdjson,
+            FormatArg::Markdown => Format::Markdown,
+            FormatArg::Html => Format::Html,
+            FormatArg::Compact => Format::Compact,
+        }
+    }
+}
+
+/// Sections of the terminal report as given to `--group-by`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum GroupByArg {
+    /// A section per file
+    File,
+    /// A section per error code
+    Code,
+}
+
+impl From<GroupByArg> for GroupBy {
+    fn from(group_by: GroupByArg) -> Self {
+        match group_by {
+            GroupByArg::File => GroupBy::File,
+            GroupByArg::Code => GroupBy::Code,
+        }
+    }
+}
+
+/// An order as given to `--sort`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum SortByArg {
+    /// By file path, then by position in the file
+    Path,
+    /// By position in the file, files stay in the order tsc reported them
+    Line,
+    /// Errors first, then warnings and advice, each in tsc order
+    Severity,
+}
+
+impl From<SortByArg> for SortBy {
+    fn from(sort: SortByArg) -> Self {
+        match sort {
+            SortByArg::Path => SortBy::Path,
+            SortByArg::Line => SortBy::Line,
+            SortByArg::Severity => SortBy::Severity,
+        }
+    }
+}
+
+/// Characters of code frames as given to `--charset`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum CharsetArg {
+    /// Box drawing characters
+    Unicode,
+    /// ASCII only, for terminals and logs that mangle box drawing characters
+    Ascii,
+}
+
+impl From<CharsetArg> for Charset {
+    fn from(charset: CharsetArg) -> Self {
+        match charset {
+            CharsetArg::Unicode => Charset::Unicode,
+            CharsetArg::Ascii => Charset::Ascii,
+        }
+    }
+}
+
+/// A `--output` value, either `<format>=<path>` or a plain path for the --format report
+#[derive(Debug, Clone)]
+struct OutputArg {
+    format: Option<Format>,
+    path:   PathBuf,
+}
+
+fn parse_output_arg(value: &str) -> Result<OutputArg, String> {
+    if let Some((format, path)) = value.split_once('=')
+        && let Ok(format) = FormatArg::from_str(format, true)
+    {
+        return Ok(OutputArg {
+            format: Some(format.into()),
+            path:   PathBuf::from(path),
+        });
+    }
+
+    Ok(OutputArg {
+        format: None,
+        path:   PathBuf::from(value),
+    })
+}
+
+/// A severity as given to `--severity` and `--fail-on`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Level {
+    Error,
+    Warning,
+    Advice,
+}
+
+impl From<Level> for Severity {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::Error => Severity::Error,
+            Level::Warning => Severity::Warning,
+            Level::Advice => Severity::Advice,
+        }
+    }
+}
+
+/// A `--severity` value, the severity every diagnostic of `code` is reported with
+#[derive(Debug, Clone, Copy)]
+struct SeverityArg {
+    code:     ErrorCode,
+    severity: Severity,
+}
+
+fn parse_severity_arg(value: &str) -> Result<SeverityArg, String> {
+    let (code, severity) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected <CODE>=<LEVEL>, got `{}`", value))?;
+
+    let code = ErrorCode::from_str(&code.trim().to_ascii_uppercase());
+    if code == ErrorCode::Unsupported(0) {
+        return Err(format!("`{}` is not a TypeScript error code", value));
     }
 
-    Ok(())
+    Ok(SeverityArg {
+        code,
+        severity: Level::from_str(severity.trim(), true)?.into(),
+    })
+}
+
+/// A report in one format and where it is written, stdout when `path` is `None`
+struct Sink {
+    format: Format,
+    path:   Option<PathBuf>,
+    color:  bool,
+}
+
+impl Sink {
+    /// Enable or disable colors for the reports rendered next
+    fn set_color(&self) {
+        colored::control::set_override(self.color);
+    }
+
+    fn write(&self, report: &str) -> Result<()> {
+        match &self.path {
+            Some(path) => std::fs::write(path, report)
+                .with_context(|| format!("failed to write report to {}", path.display())),
+            None => {
+                print!("{}", report);
+                Ok(())
+            }
+        }
+    }
 }
 
-fn format_lsp_diagnostic(
+/// Every report of the run. The --format report goes to stdout unless a plain `--output <path>`
+/// redirects it.
+fn sinks(format: Format, outputs: Vec<OutputArg>, color: ColorChoice) -> Vec<Sink> {
+    let mut sinks = Vec::new();
+
+    if outputs.iter().all(|output| output.format.is_some()) {
+        let color = match color {
+            ColorChoice::Auto => colored::control::ShouldColorize::from_env().should_colorize(),
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        };
+        sinks.push(Sink {
+            format,
+            path: None,
+            color,
+        });
+    }
+
+    sinks.extend(outputs.into_iter().map(|output| Sink {
+        format: output.format.unwrap_or(format),
+        path:   Some(output.path),
+        color:  color == ColorChoice::Always,
+    }));
+    sinks
+}
+
+fn lsp_diagnostic(
     file: String, line: usize, column: usize, code: String, message: String,
-) -> Result<()> {
-    let parsed = error::TsError {
+    severities: &[SeverityArg],
+) -> Diagnostic {
+    let parsed = TsError::new(
         file,
         line,
         column,
-        code: ErrorCode::from_str(&code),
-        message,
-    };
+        ErrorCode::from_str(&code),
+        Category::Error,
+        &message,
+    );
 
-    println!("{}", formatter::fmt(&parsed));
-    Ok(())
-}
-
-fn parse_tsc_output(input: Option<String>) -> Result<()> {
-    let buf: String;
-
-    if let Some(input_file) = input {
-        // Execute tsc on a specific file
-        // Note: When tsc is run with a file argument, it doesn't use tsconfig.json
-        // So we need to pass compiler options explicitly
-        let output = std::process::Command::new("tsc")
-            .arg(&input_file)
-            .args([
-                "--pretty",
-                "false",
-                "--diagnostics",
-                "--extendedDiagnostics",
-                "--noEmit",
-                "--preserveWatchOutput",
-                "false",
-                "--noUnusedLocals",
-                "--noUnusedParameters",
-                "--strict",
-            ])
-            .output()?;
-        buf = format!(
-            "{}{}",
-            String::from_utf8_lossy(&output.stdout),
-            String::from_utf8_lossy(&output.stderr)
-        );
-    } else {
-        // Execute tsc in the current directory
-        let output = std::process::Command::new("tsc")
-            .args([
-                "--pretty",
-                "false",
-                "--diagnostics",
-                "--extendedDiagnostics",
-                "--noEmit",
-                "--preserveWatchOutput",
-                "false",
-            ])
-            .output()?;
-        buf = format!(
-            "{}{}",
-            String::from_utf8_lossy(&output.stdout),
-            String::from_utf8_lossy(&output.stderr)
-        );
+    enrich(parsed, severities)
+}
+
+/// Enrich an error, reported with the severity `--severity` sets for its code
+fn enrich(mut err: TsError, severities: &[SeverityArg]) -> Diagnostic {
+    if let Some(arg) = severities.iter().rev().find(|arg| arg.code == err.code) {
+        err.severity = arg.severity;
     }
 
-    if buf.is_empty() {
-        println!("No output from tsc.");
-        return Ok(());
+    ts_analyzer::enrich(err)
+}
+
+/// Write the reports for tsc output to every sink, returns its diagnostics
+fn parse_tsc_output(
+    buf: String, sinks: &[Sink], sort: Option<SortBy>, severities: &[SeverityArg],
+    options: &TerminalOptions,
+) -> Result<Vec<Diagnostic>> {
+    let mut diagnostics: Vec<Diagnostic> = ts_analyzer::parse_output(&buf)
+        .into_iter()
+        .map(|err| enrich(err, severities))
+        .collect();
+
+    if let Some(sort) = sort {
+        ts_analyzer::sort(&mut diagnostics, sort);
+    }
+
+    for sink in sinks {
+        sink.set_color();
+        if sink.format != Format::Terminal {
+            sink.write(&ts_analyzer::render_report(sink.format, &diagnostics))?;
+        } else if buf.is_empty() {
+            sink.write("No output from tsc.\n")?;
+        } else {
+            sink.write(&ts_analyzer::render_terminal(&diagnostics, options))?;
+        }
     }
 
-    let mut found_error = false;
-    let mut counter: usize = 0;
-    let lines: Vec<&str> = buf.lines().collect();
-    let mut i = 0;
+    Ok(diagnostics)
+}
 
-    while i < lines.len() {
-        if let Some(mut parsed) = error::parse(lines[i]) {
-            found_error = true;
-            counter += 1;
+/// Write the reports for `tsc --build` output to every sink, returns its diagnostics
+fn parse_build_output(
+    buf: String, sinks: &[Sink], sort: Option<SortBy>, severities: &[SeverityArg],
+    options: &TerminalOptions,
+) -> Result<Vec<Diagnostic>> {
+    let cwd = std::env::current_dir()?;
+    let mut diagnostics = Vec::new();
+    // Name of every project with the range of its diagnostics
+    let mut projects = Vec::new();
 
-            // Collect continuation lines (indented lines following the error)
-            let mut indented_line = i + 1;
-            while indented_line < lines.len() && lines[indented_line].starts_with("  ") {
-                parsed.message.push('\n');
-                parsed.message.push_str(lines[indented_line].trim());
-                indented_line += 1;
-            }
+    for project in ts_analyzer::parse_build_output(&buf) {
+        let start = diagnostics.len();
+        diagnostics.extend(
+            project
+                .errors
+                .into_iter()
+                .map(|err| enrich(err, severities)),
+        );
+        if let Some(sort) = sort {
+            ts_analyzer::sort(&mut diagnostics[start..], sort);
+        }
+        projects.push((
+            project_name(project.project.as_deref(), &cwd),
+            start..diagnostics.len(),
+        ));
+    }
 
-            println!("{}", formatter::fmt(&parsed));
-            i = indented_line;
+    for sink in sinks {
+        sink.set_color();
+        // Only the terminal report has per-project sections, other formats list every diagnostic
+        if sink.format != Format::Terminal {
+            sink.write(&ts_analyzer::render_report(sink.format, &diagnostics))?;
+        } else if buf.is_empty() {
+            sink.write("No output from tsc.\n")?;
         } else {
-            i += 1;
+            sink.write(&build_summary(&diagnostics, &projects, options)?)?;
         }
     }
-    if !found_error {
-        println!("No errors were emitted.");
+
+    Ok(diagnostics)
+}
+
+/// Terminal report for `tsc --build` with a section per project and a summary table
+fn build_summary(
+    diagnostics: &[Diagnostic], projects: &[(String, std::ops::Range<usize>)],
+    options: &TerminalOptions,
+) -> Result<String> {
+    let mut report = String::new();
+
+    for (name, range) in projects {
+        writeln!(report, "\n{} {}\n", "Project".bold(), name.cyan().bold())?;
+        report.push_str(&ts_analyzer::terminal_reports(
+            &diagnostics[range.clone()],
+            options,
+        ));
+        if range.is_empty() {
+            writeln!(report, "No errors were emitted.")?;
+        }
     }
 
-    let counter_str = counter.to_string();
+    if projects.is_empty() {
+        writeln!(report, "No projects were built, everything is up to date.")?;
+    }
+
+    let width = projects
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0);
 
-    println!("\nTotal errors: {}", counter_str.red().bold());
+    writeln!(report, "\n{}", "Summary".bold())?;
+    for (name, range) in projects {
+        let counter_str = range.len().to_string();
+        let counter_str = if range.is_empty() {
+            counter_str.green()
+        } else {
+            counter_str.red()
+        };
+
+        writeln!(report, "  {:<width$}  {}", name, counter_str.bold())?;
+    }
 
-    Ok(())
+    writeln!(report, "\n{}", ts_analyzer::terminal_totals(diagnostics))?;
+
+    Ok(report)
+}
+
+/// Display a project path relative to the current directory
+fn project_name(project: Option<&str>, cwd: &std::path::Path) -> String {
+    match project {
+        Some(project) => std::path::Path::new(project)
+            .strip_prefix(cwd)
+            .map(|path| path.display().to_string())
+            .unwrap_or_else(|_| project.to_string()),
+        None => "(build)".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_output_arg() {
+        let arg = parse_output_arg("sarif=out/report.sarif").unwrap();
+        assert_eq!(arg.format, Some(Format::Sarif));
+        assert_eq!(arg.path, PathBuf::from("out/report.sarif"));
+
+        let arg = parse_output_arg("report.txt").unwrap();
+        assert_eq!(arg.format, None);
+        assert_eq!(arg.path, PathBuf::from("report.txt"));
+
+        // Anything before `=` that is not a format is part of the path
+        let arg = parse_output_arg("build=1/report.txt").unwrap();
+        assert_eq!(arg.format, None);
+        assert_eq!(arg.path, PathBuf::from("build=1/report.txt"));
+    }
+
+    /// Format, path and color of every sink for the given `--output` values
+    fn targets(
+        format: Format, outputs: &[&str], color: ColorChoice,
+    ) -> Vec<(Format, Option<String>, bool)> {
+        let outputs = outputs
+            .iter()
+            .map(|arg| parse_output_arg(arg).unwrap())
+            .collect();
+
+        sinks(format, outputs, color)
+            .into_iter()
+            .map(|sink| {
+                (
+                    sink.format,
+                    sink.path.map(|path| path.display().to_string()),
+                    sink.color,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sinks() {
+        assert_eq!(
+            targets(
+                Format::Terminal,
+                &["sarif=a.sarif", "junit=a.xml"],
+                ColorChoice::Always
+            ),
+            vec![
+                (Format::Terminal, None, true),
+                (Format::Sarif, Some("a.sarif".to_string()), true),
+                (Format::Junit, Some("a.xml".to_string()), true),
+            ]
+        );
+
+        // A plain path takes the --format report off stdout
+        assert_eq!(
+            targets(
+                Format::Json,
+                &["report.json", "sarif=a.sarif"],
+                ColorChoice::Never
+            ),
+            vec![
+                (Format::Json, Some("report.json".to_string()), false),
+                (Format::Sarif, Some("a.sarif".to_string()), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_severity_arg() {
+        let arg = parse_severity_arg("ts80001=advice").unwrap();
+        assert_eq!(arg.code.to_string(), "TS80001");
+        assert_eq!(arg.severity, Severity::Advice);
+
+        let arg = parse_severity_arg("TS6133=Error").unwrap();
+        assert_eq!(arg.code, ErrorCode::DeclaredButNeverUsed);
+        assert_eq!(arg.severity, Severity::Error);
+
+        assert!(parse_severity_arg("TS6133").is_err());
+        assert!(parse_severity_arg("unused=warning").is_err());
+        assert!(parse_severity_arg("TS6133=fatal").is_err());
+    }
 }
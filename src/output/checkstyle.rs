@@ -0,0 +1,88 @@
+use crate::{
+    diagnostics::Diagnostic,
+    error::Severity,
+    output::{
+        by_file,
+        file_name,
+        suggestion_lines,
+        xml_escape,
+    },
+};
+
+/// Render diagnostics as a Checkstyle XML report grouped by file
+pub fn render(diagnostics: &[Diagnostic]) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<checkstyle version=\"4.3\">\n");
+
+    for (file, diagnostics) in by_file(diagnostics) {
+        out.push_str(&format!(
+            "  <file name=\"{}\">\n",
+            xml_escape(file_name(file))
+        ));
+
+        for diagnostic in diagnostics {
+            let err = &diagnostic.error;
+            let location = if err.is_global() {
+                String::new()
+            } else {
+                format!("line=\"{}\" column=\"{}\" ", err.line, err.column)
+            };
+
+            // Attribute values can not hold line breaks, so elaborations and suggestions are
+            // joined into a single line
+            let mut message: Vec<String> = err.full_message().lines().map(str::to_string).collect();
+            message.extend(suggestion_lines(diagnostic));
+
+            out.push_str(&format!(
+                "    <error {}severity=\"{}\" message=\"{}\" source=\"ts-analyzer.{}\"/>\n",
+                location,
+                severity(err.severity),
+                xml_escape(&message.join(" ")),
+                err.code
+            ));
+        }
+
+        out.push_str("  </file>\n");
+    }
+
+    out.push_str("</checkstyle>\n");
+    out
+}
+
+fn severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Advice => "info",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::test_diagnostics;
+
+    #[test]
+    fn test_checkstyle_render() {
+        let out = render(&test_diagnostics([
+            "src/a.ts(1,7): error TS2304: Cannot find name 'foo'.",
+        ]));
+
+        assert!(out.contains("<file name=\"src/a.ts\">"));
+        assert!(out.contains(
+            "<error line=\"1\" column=\"7\" severity=\"error\" message=\"Cannot find name \
+             &apos;foo&apos;. Identifier `foo` can not be found"
+        ));
+        assert!(out.contains("source=\"ts-analyzer.TS2304\"/>"));
+    }
+
+    #[test]
+    fn test_checkstyle_global_file_name() {
+        let out = render(&test_diagnostics([
+            "error TS18003: No inputs were found in config file.",
+        ]));
+
+        assert!(out.contains("<file name=\"(global)\">"));
+        assert!(out.contains("<error severity=\"error\""));
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{
+    diagnostics::Diagnostic,
+    error::Severity,
+    output::suggestion_lines,
+};
+
+/// Render diagnostics one per line as `file:line:col: error TS2322: message [hint: ...]`, the
+/// shape understood by Vim's errorformat, Emacs compilation-mode and grep
+pub fn render(diagnostics: &[Diagnostic]) -> String {
+    diagnostics
+        .iter()
+        .map(|diagnostic| line(diagnostic) + "\n")
+        .collect()
+}
+
+fn line(diagnostic: &Diagnostic) -> String {
+    let err = &diagnostic.error;
+
+    let message: Vec<String> = err
+        .full_message()
+        .lines()
+        .map(|line| line.trim().to_string())
+        .collect();
+    let mut out = format!(
+        "{} {}: {}",
+        severity(err.severity),
+        err.code,
+        message.join(" ")
+    );
+
+    let hints = suggestion_lines(diagnostic);
+    if !hints.is_empty() {
+        out.push_str(&format!(" [hint: {}]", hints.join("; ")));
+    }
+
+    if err.is_global() {
+        out
+    } else {
+        format!("{}:{}:{}: {}", err.file, err.line, err.column, out)
+    }
+}
+
+/// Severity as a word whose first letter errorformat's `%t` understands
+fn severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Advice => "info",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::test_diagnostics;
+
+    #[test]
+    fn test_compact_render() {
+        let out = render(&test_diagnostics([
+            "src/a.ts(1,7): error TS2304: Cannot find name 'foo'.",
+            "error TS9999: Something global.",
+            "src/a.ts(2,1): error TS95050: Unreachable code detected.",
+        ]));
+        let lines: Vec<&str> = out.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with(
+            "src/a.ts:1:7: error TS2304: Cannot find name 'foo'. [hint: Identifier `foo` can not \
+             be found in the current scope.; help: "
+        ));
+        assert!(lines[0].ends_with(']'));
+        assert_eq!(lines[1], "error TS9999: Something global.");
+        assert!(lines[2].starts_with("src/a.ts:2:1: info TS95050: Unreachable code detected."));
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{
+    diagnostics::Diagnostic,
+    error::Severity,
+    output::suggestion_lines,
+};
+
+/// Render diagnostics as GitHub Actions workflow commands, which show up as inline annotations
+/// on pull requests
+pub fn render(diagnostics: &[Diagnostic]) -> String {
+    let mut out = String::new();
+
+    for diagnostic in diagnostics {
+        let err = &diagnostic.error;
+        let command = match err.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Advice => "notice",
+        };
+
+        let mut properties = Vec::new();
+        if !err.is_global() {
+            properties.push(format!("file={}", escape_property(&err.file)));
+            properties.push(format!("line={}", err.line));
+            properties.push(format!("col={}", err.column));
+
+            if let Some((end_line, end_column)) = diagnostic.end_position() {
+                properties.push(format!("endLine={}", end_line));
+                properties.push(format!("endColumn={}", end_column));
+            }
+        }
+        properties.push(format!("title={}", err.code));
+
+        let mut message = err.full_message();
+        for line in suggestion_lines(diagnostic) {
+            message.push('\n');
+            message.push_str(&line);
+        }
+
+        out.push_str(&format!(
+            "::{} {}::{}\n",
+            command,
+            properties.join(","),
+            escape_data(&message)
+        ));
+    }
+
+    out
+}
+
+/// Escape the message of a workflow command
+fn escape_data(data: &str) -> String {
+    data.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a property value of a workflow command
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::test_diagnostics;
+
+    #[test]
+    fn test_github_render() {
+        let out = render(&test_diagnostics([
+            "C:\\a,b.ts(1,7): error TS2304: Cannot find name 'foo'.",
+        ]));
+
+        assert!(out.starts_with("::error file=C%3A\\a%2Cb.ts,line=1,col=7,title=TS2304::"));
+        assert!(out.contains("Cannot find name 'foo'.%0AIdentifier `foo`"));
+        assert_eq!(out.lines().count(), 1);
+    }
+}
@@ -0,0 +1,113 @@
+use serde::Serialize;
+
+use crate::{
+    diagnostics::Diagnostic,
+    error::Severity,
+    output::{
+        fingerprints,
+        suggestion_lines,
+    },
+};
+
+/// Path reported for global diagnostics, GitLab requires every issue to have one and global
+/// diagnostics are almost always about the compiler configuration
+const GLOBAL_PATH: &str = "tsconfig.json";
+
+#[derive(Serialize)]
+struct Issue {
+    description: String,
+    check_name:  String,
+    fingerprint: String,
+    severity:    &'static str,
+    location:    Location,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content:     Option<Content>,
+}
+
+#[derive(Serialize)]
+struct Location {
+    path:  String,
+    lines: Lines,
+}
+
+#[derive(Serialize)]
+struct Lines {
+    begin: usize,
+}
+
+#[derive(Serialize)]
+struct Content {
+    body: String,
+}
+
+/// Render diagnostics as a GitLab Code Quality report
+pub fn render(diagnostics: &[Diagnostic]) -> String {
+    let issues: Vec<Issue> = diagnostics
+        .iter()
+        .zip(fingerprints(diagnostics))
+        .map(|(diagnostic, fingerprint)| {
+            let err = &diagnostic.error;
+            let suggestions = suggestion_lines(diagnostic);
+
+            Issue {
+                description: err.full_message(),
+                check_name: err.code.to_string(),
+                fingerprint,
+                severity: severity(err.severity),
+                location: Location {
+                    path:  if err.is_global() {
+                        GLOBAL_PATH.to_string()
+                    } else {
+                        err.file.clone()
+                    },
+                    lines: Lines {
+                        begin: err.line.max(1),
+                    },
+                },
+                content: (!suggestions.is_empty()).then(|| Content {
+                    body: suggestions.join("\n"),
+                }),
+            }
+        })
+        .collect();
+
+    let mut out = serde_json::to_string_pretty(&issues).unwrap_or_default();
+    out.push('\n');
+    out
+}
+
+fn severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "major",
+        Severity::Warning => "minor",
+        Severity::Advice => "info",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::test_diagnostics;
+
+    #[test]
+    fn test_gitlab_render() {
+        let diagnostics = test_diagnostics([
+            "src/a.ts(1,7): error TS2304: Cannot find name 'foo'.",
+            "error TS18003: No inputs were found in config file 'tsconfig.json'.",
+        ]);
+        let issues: serde_json::Value = serde_json::from_str(&render(&diagnostics)).unwrap();
+
+        assert_eq!(issues[0]["check_name"], "TS2304");
+        assert_eq!(issues[0]["severity"], "major");
+        assert_eq!(issues[0]["location"]["path"], "src/a.ts");
+        assert_eq!(issues[0]["location"]["lines"]["begin"], 1);
+        assert!(
+            issues[0]["content"]["body"]
+                .as_str()
+                .unwrap()
+                .contains("Identifier `foo`")
+        );
+        assert_eq!(issues[1]["location"]["path"], GLOBAL_PATH);
+        assert_ne!(issues[0]["fingerprint"], issues[1]["fingerprint"]);
+    }
+}
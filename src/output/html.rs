@@ -0,0 +1,321 @@
+use std::{
+    collections::HashMap,
+    fmt::Write,
+    ops::Range,
+};
+
+use crate::{
+    diagnostics::{
+        Diagnostic,
+        styled::{
+            Style,
+            StyledText,
+        },
+    },
+    output::{
+        by_file,
+        file_name,
+        group,
+        xml_escape as escape,
+    },
+    tokenizer::{
+        Token,
+        TokenKind,
+        Tokenizer,
+    },
+};
+
+/// Lines of source shown around the reported line
+const CONTEXT_LINES: usize = 2;
+
+const STYLE: &str = r#"
+body { margin: 0; font: 14px/1.5 system-ui, sans-serif; color: #1f2328; background: #f6f8fa; }
+header { padding: 16px 24px; background: #24292f; color: #fff; }
+header h1 { margin: 0 0 4px; font-size: 20px; }
+header select { margin-top: 8px; font: inherit; }
+.layout { display: flex; align-items: flex-start; }
+nav { position: sticky; top: 0; width: 280px; max-height: 100vh; overflow: auto; padding: 16px; box-sizing: border-box; }
+nav a { display: block; padding: 2px 4px; color: #0969da; text-decoration: none; word-break: break-all; }
+nav a span { color: #656d76; }
+main { flex: 1; min-width: 0; padding: 16px 24px; }
+section.file > h2 { font: 600 16px ui-monospace, monospace; word-break: break-all; }
+article { margin: 0 0 16px; padding: 12px 16px; border: 1px solid #d0d7de; border-left: 4px solid #cf222e; border-radius: 6px; background: #fff; }
+article.warning { border-left-color: #9a6700; }
+article.advice { border-left-color: #0969da; }
+article h3 { margin: 0 0 8px; font-size: 14px; }
+article h3 .location { color: #656d76; font-weight: normal; }
+pre { margin: 8px 0; padding: 8px; overflow: auto; background: #f6f8fa; border-radius: 6px; font: 13px/1.45 ui-monospace, monospace; }
+pre.message { white-space: pre-wrap; background: none; padding: 0; }
+.frame .ln { display: inline-block; min-width: 3em; padding-right: 8px; color: #8c959f; text-align: right; user-select: none; }
+.frame .current .ln { color: #cf222e; font-weight: bold; }
+.frame mark { background: #ffebe9; text-decoration: underline wavy #cf222e; }
+.kw { color: #cf222e; } .id { color: #8250df; } .lit { color: #0a3069; } .sym { color: #6e7781; }
+ul.suggestions { margin: 8px 0; padding-left: 20px; }
+code.identifier { color: #cf222e; } code.type { color: #9a6700; } code.suggested { color: #1a7f37; }
+.help { margin: 8px 0 0; color: #1a7f37; }
+.hidden { display: none; }
+"#;
+
+/// Hide diagnostics of other codes, and files without any diagnostic left
+const SCRIPT: &str = r##"
+document.getElementById("code-filter").addEventListener("change", (event) => {
+  const code = event.target.value;
+  document.querySelectorAll("article").forEach((article) => {
+    article.classList.toggle("hidden", code !== "" && article.dataset.code !== code);
+  });
+  document.querySelectorAll("section.file").forEach((section) => {
+    const visible = section.querySelectorAll("article:not(.hidden)").length;
+    section.classList.toggle("hidden", visible === 0);
+    document.querySelector(`nav a[href="#${section.id}"]`).classList.toggle("hidden", visible === 0);
+  });
+});
+"##;
+
+/// Render diagnostics as a self-contained HTML report that works offline
+pub fn render(diagnostics: &[Diagnostic]) -> String {
+    let files = by_file(diagnostics);
+
+    let mut codes: Vec<(String, usize)> = group(diagnostics, |d| d.error.code.to_string())
+        .into_iter()
+        .map(|(code, diagnostics)| (code, diagnostics.len()))
+        .collect();
+    codes.sort();
+
+    let mut out = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+    out.push_str("<meta charset=\"utf-8\">\n<title>ts-analyzer report</title>\n");
+    let _ = writeln!(out, "<style>{}</style>\n</head>\n<body>", STYLE);
+
+    let _ = writeln!(
+        out,
+        "<header>\n<h1>ts-analyzer report</h1>\n<div>{} diagnostics in {} files</div>",
+        diagnostics.len(),
+        files.len()
+    );
+    out.push_str("<select id=\"code-filter\">\n<option value=\"\">All codes</option>\n");
+    for (code, count) in &codes {
+        let _ = writeln!(out, "<option value=\"{0}\">{0} ({1})</option>", code, count);
+    }
+    out.push_str("</select>\n</header>\n<div class=\"layout\">\n<nav>\n");
+
+    for (idx, (file, diagnostics)) in files.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "<a href=\"#file-{}\">{} <span>({})</span></a>",
+            idx,
+            escape(file_name(file)),
+            diagnostics.len()
+        );
+    }
+    out.push_str("</nav>\n<main>\n");
+
+    if diagnostics.is_empty() {
+        out.push_str("<p>No errors were emitted.</p>\n");
+    }
+
+    for (idx, (file, diagnostics)) in files.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "<section class=\"file\" id=\"file-{}\">\n<h2>{}</h2>",
+            idx,
+            escape(file_name(file))
+        );
+
+        // Every diagnostic of a file shares its source, so it is only tokenized once
+        let mut tokens: HashMap<&str, Vec<Token>> = HashMap::new();
+        for diagnostic in diagnostics {
+            let tokens = tokens
+                .entry(diagnostic.source.as_str())
+                .or_insert_with(|| Tokenizer::new(diagnostic.source.clone()).tokenize());
+            article(&mut out, diagnostic, tokens);
+        }
+
+        out.push_str("</section>\n");
+    }
+
+    let _ = writeln!(
+        out,
+        "</main>\n</div>\n<script>{}</script>\n</body>\n</html>",
+        SCRIPT
+    );
+    out
+}
+
+/// A single diagnostic with its code frame, message, suggestions and help
+fn article(out: &mut String, diagnostic: &Diagnostic, tokens: &[Token]) {
+    let err = &diagnostic.error;
+
+    let _ = writeln!(
+        out,
+        "<article class=\"{}\" data-code=\"{}\">",
+        err.severity.as_str(),
+        err.code
+    );
+    if err.is_global() {
+        let _ = writeln!(out, "<h3>{}</h3>", err.code);
+    } else {
+        let _ = writeln!(
+            out,
+            "<h3>{} <span class=\"location\">line {}, column {}</span></h3>",
+            err.code, err.line, err.column
+        );
+    }
+
+    let _ = writeln!(
+        out,
+        "<pre class=\"message\">{}</pre>",
+        escape(&err.full_message())
+    );
+
+    if !diagnostic.source.is_empty() && err.line > 0 {
+        frame(out, diagnostic, tokens);
+    }
+
+    if let Some(suggestion) = &diagnostic.suggestion {
+        if suggestion.label.is_some() || !suggestion.notes.is_empty() {
+            out.push_str("<ul class=\"suggestions\">\n");
+            for text in suggestion.messages() {
+                let _ = writeln!(out, "<li>{}</li>", styled(text));
+            }
+            out.push_str("</ul>\n");
+        }
+        if let Some(help) = &suggestion.help {
+            let _ = writeln!(
+                out,
+                "<p class=\"help\"><strong>help:</strong> {}</p>",
+                styled(help)
+            );
+        }
+    }
+
+    out.push_str("</article>\n");
+}
+
+/// Syntax highlighted lines around the error with its span marked
+fn frame(out: &mut String, diagnostic: &Diagnostic, tokens: &[Token]) {
+    let source = &diagnostic.source;
+    let line = diagnostic.error.line;
+    let first = line.saturating_sub(CONTEXT_LINES).max(1);
+    let span = diagnostic.label_span();
+
+    out.push_str("<pre class=\"frame\">");
+
+    let mut offset = 0;
+    for (idx, text) in source.split_inclusive('\n').enumerate() {
+        let number = idx + 1;
+        let range = offset..offset + text.trim_end_matches(['\r', '\n']).len();
+        offset += text.len();
+
+        if number < first {
+            continue;
+        }
+        if number > line + CONTEXT_LINES {
+            break;
+        }
+
+        let class = if number == line {
+            " class=\"current\""
+        } else {
+            ""
+        };
+        let _ = write!(
+            out,
+            "<div{}><span class=\"ln\">{}</span>{}</div>",
+            class,
+            number,
+            highlight(source, range, tokens, &span)
+        );
+    }
+
+    out.push_str("</pre>\n");
+}
+
+/// HTML of a line of source, split into segments at token and span boundaries
+fn highlight(source: &str, line: Range<usize>, tokens: &[Token], span: &Range<usize>) -> String {
+    let tokens: Vec<&Token> = tokens
+        .iter()
+        .filter(|token| token.start < line.end && token.end > line.start)
+        .collect();
+
+    let mut boundaries = vec![line.start, line.end];
+    for token in &tokens {
+        boundaries.extend([token.start, token.end]);
+    }
+    boundaries.extend([span.start, span.end]);
+    boundaries.retain(|offset| line.contains(offset) || *offset == line.end);
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    let mut out = String::new();
+    for segment in boundaries.windows(2) {
+        let (start, end) = (segment[0], segment[1]);
+        let Some(text) = source.get(start..end) else {
+            continue;
+        };
+
+        let mut html = escape(text);
+        if let Some(token) = tokens
+            .iter()
+            .find(|token| token.start <= start && end <= token.end)
+        {
+            html = format!("<span class=\"{}\">{}</span>", class(&token.kind), html);
+        }
+        if span.start <= start && end <= span.end {
+            html = format!("<mark>{}</mark>", html);
+        }
+        out.push_str(&html);
+    }
+
+    out
+}
+
+/// HTML of suggestion text, code segments are classed by what they refer to
+fn styled(text: &StyledText) -> String {
+    text.segments
+        .iter()
+        .map(|segment| {
+            let class = match segment.style {
+                Style::Plain => return escape(&segment.text),
+                Style::Label => return format!("<strong>{}</strong>", escape(&segment.text)),
+                Style::Code => "code",
+                Style::Type => "type",
+                Style::Identifier => "identifier",
+                Style::Suggested => "suggested",
+            };
+            format!("<code class=\"{}\">{}</code>", class, escape(&segment.text))
+        })
+        .collect()
+}
+
+fn class(kind: &TokenKind) -> &'static str {
+    match kind {
+        TokenKind::Keyword => "kw",
+        TokenKind::Identifier => "id",
+        TokenKind::Literal => "lit",
+        _ => "sym",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::parse;
+
+    #[test]
+    fn test_html_render() {
+        let err = parse("a.ts(2,11): error TS2304: Cannot find name 'foo'.").unwrap();
+        let source = "const a = 1;\nconst b = foo < 2;\n";
+        let out = render(&[Diagnostic::with_source(err, source.to_string())]);
+
+        assert!(out.contains("<option value=\"TS2304\">TS2304 (1)</option>"));
+        assert!(out.contains("<a href=\"#file-0\">a.ts <span>(1)</span></a>"));
+        assert!(out.contains("<article class=\"error\" data-code=\"TS2304\">"));
+        assert!(out.contains(
+            "<div class=\"current\"><span class=\"ln\">2</span><span class=\"kw\">const</span> \
+             <span class=\"id\">b</span> <span class=\"sym\">=</span> <mark><span \
+             class=\"id\">foo</span></mark> <span class=\"sym\">&lt;</span>"
+        ));
+        assert!(
+            out.contains("<li>Identifier <code class=\"identifier\">foo</code> can not be found")
+        );
+    }
+}
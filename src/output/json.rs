@@ -0,0 +1,122 @@
+use serde::Serialize;
+
+use crate::diagnostics::{
+    Diagnostic,
+    styled::StyledText,
+};
+
+/// Version of the JSON document, bumped on any breaking change to its shape
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct Report<'a> {
+    version:     u32,
+    diagnostics: Vec<JsonDiagnostic<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonDiagnostic<'a> {
+    file:       Option<&'a str>,
+    line:       Option<usize>,
+    column:     Option<usize>,
+    code:       String,
+    category:   &'static str,
+    severity:   &'static str,
+    message:    String,
+    related:    Vec<JsonRelated<'a>>,
+    suggestion: Option<JsonSuggestion>,
+}
+
+#[derive(Serialize)]
+struct JsonRelated<'a> {
+    file:    &'a str,
+    line:    usize,
+    column:  usize,
+    message: &'a str,
+}
+
+#[derive(Serialize)]
+struct JsonSuggestion {
+    suggestions: Vec<String>,
+    help:        Option<String>,
+    span:        Option<JsonSpan>,
+}
+
+#[derive(Serialize)]
+struct JsonSpan {
+    start: usize,
+    end:   usize,
+}
+
+/// Render diagnostics as a versioned JSON document without any terminal styling
+pub fn render(diagnostics: &[Diagnostic]) -> String {
+    let report = Report {
+        version:     SCHEMA_VERSION,
+        diagnostics: diagnostics.iter().map(json_diagnostic).collect(),
+    };
+
+    let mut out = serde_json::to_string_pretty(&report).unwrap_or_default();
+    out.push('\n');
+    out
+}
+
+fn json_diagnostic(diagnostic: &Diagnostic) -> JsonDiagnostic<'_> {
+    let err = &diagnostic.error;
+    let location = !err.is_global();
+
+    JsonDiagnostic {
+        file:       location.then_some(err.file.as_str()),
+        line:       location.then_some(err.line),
+        column:     location.then_some(err.column),
+        code:       err.code.to_string(),
+        category:   err.category.as_str(),
+        severity:   err.severity.as_str(),
+        message:    err.full_message(),
+        related:    err
+            .related
+            .iter()
+            .map(|info| JsonRelated {
+                file:    &info.file,
+                line:    info.line,
+                column:  info.column,
+                message: &info.message,
+            })
+            .collect(),
+        suggestion: diagnostic.suggestion.as_ref().map(|s| {
+            // Spans are only meaningful when the source could be read
+            let span = (!diagnostic.source.is_empty()).then(|| {
+                let span = diagnostic.label_span();
+                JsonSpan {
+                    start: span.start,
+                    end:   span.end,
+                }
+            });
+
+            JsonSuggestion {
+                suggestions: s.messages().map(StyledText::plain).collect(),
+                help: s.help.as_ref().map(StyledText::plain),
+                span,
+            }
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::test_diagnostics;
+
+    #[test]
+    fn test_json_render() {
+        let diagnostics = test_diagnostics(["error TS5023: Unknown compiler option 'foo'."]);
+        let json: serde_json::Value = serde_json::from_str(&render(&diagnostics)).unwrap();
+
+        assert_eq!(json["version"], SCHEMA_VERSION);
+        assert_eq!(json["diagnostics"][0]["code"], "TS5023");
+        assert_eq!(json["diagnostics"][0]["file"], serde_json::Value::Null);
+        assert_eq!(
+            json["diagnostics"][0]["suggestion"]["suggestions"][0],
+            "`foo` is not a known compiler option."
+        );
+    }
+}
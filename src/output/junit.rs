@@ -0,0 +1,82 @@
+use crate::{
+    diagnostics::Diagnostic,
+    output::{
+        by_file,
+        file_name,
+        suggestion_lines,
+        xml_escape,
+    },
+};
+
+/// Render diagnostics as a JUnit XML report with one testsuite per file and one failing testcase
+/// per diagnostic
+pub fn render(diagnostics: &[Diagnostic]) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+
+    out.push_str(&format!(
+        "<testsuites name=\"ts-analyzer\" tests=\"{0}\" failures=\"{0}\">\n",
+        diagnostics.len()
+    ));
+
+    for (file, diagnostics) in by_file(diagnostics) {
+        let name = file_name(file);
+
+        out.push_str(&format!(
+            "  <testsuite name=\"{0}\" tests=\"{1}\" failures=\"{1}\">\n",
+            xml_escape(name),
+            diagnostics.len()
+        ));
+
+        for diagnostic in diagnostics {
+            let err = &diagnostic.error;
+            let case = if err.is_global() {
+                err.code.to_string()
+            } else {
+                format!("{} at {}:{}", err.code, err.line, err.column)
+            };
+
+            let mut body = vec![err.full_message()];
+            body.extend(suggestion_lines(diagnostic));
+
+            out.push_str(&format!(
+                "    <testcase classname=\"{}\" name=\"{}\">\n",
+                xml_escape(name),
+                xml_escape(&case)
+            ));
+            out.push_str(&format!(
+                "      <failure type=\"{}\" message=\"{}\">{}</failure>\n",
+                err.code,
+                xml_escape(&err.message),
+                xml_escape(&body.join("\n"))
+            ));
+            out.push_str("    </testcase>\n");
+        }
+
+        out.push_str("  </testsuite>\n");
+    }
+
+    out.push_str("</testsuites>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::test_diagnostics;
+
+    #[test]
+    fn test_junit_render() {
+        let out = render(&test_diagnostics([
+            "src/a.ts(1,7): error TS2304: Cannot find name 'foo'.",
+            "src/b.ts(2,1): error TS2304: Cannot find name 'bar'.",
+            "src/a.ts(3,1): error TS2304: Cannot find name 'baz'.",
+        ]));
+
+        assert!(out.contains("<testsuites name=\"ts-analyzer\" tests=\"3\" failures=\"3\">"));
+        assert!(out.contains("<testsuite name=\"src/a.ts\" tests=\"2\" failures=\"2\">"));
+        assert!(out.contains("<testcase classname=\"src/a.ts\" name=\"TS2304 at 3:1\">"));
+        assert!(out.contains("message=\"Cannot find name &apos;foo&apos;.\""));
+        assert!(out.contains("Identifier `foo` can not be found"));
+        assert_eq!(out.matches("<testsuite ").count(), 2);
+    }
+}
@@ -0,0 +1,312 @@
+use std::fmt::Write;
+
+use crate::{
+    diagnostics::{
+        Diagnostic,
+        styled::{
+            Style,
+            StyledText,
+        },
+    },
+    output::{
+        by_file,
+        file_name,
+    },
+};
+
+/// Most diagnostics shown in detail, the summary tables still count every diagnostic
+const MAX_DIAGNOSTICS: usize = 50;
+/// Most rows of a summary table
+const MAX_ROWS: usize = 20;
+/// Stop adding details past this length, GitHub rejects comments over 65536 characters
+const MAX_LENGTH: usize = 60_000;
+/// Lines of source shown around the reported line
+const CONTEXT_LINES: usize = 1;
+/// Closes the collapsible section of a file
+const SECTION_END: &str = "</details>\n\n";
+
+/// Render diagnostics as a Markdown report sized for pull request comments
+pub fn render(diagnostics: &[Diagnostic]) -> String {
+    let mut out = String::from("## ts-analyzer\n\n");
+
+    if diagnostics.is_empty() {
+        out.push_str("No errors were emitted.\n");
+        return out;
+    }
+
+    let files = by_file(diagnostics);
+    let _ = writeln!(
+        out,
+        "**{}** in **{}**\n",
+        plural(diagnostics.len(), "diagnostic"),
+        plural(files.len(), "file")
+    );
+
+    let mut codes: Vec<(String, usize)> = Vec::new();
+    for diagnostic in diagnostics {
+        let code = diagnostic.error.code.to_string();
+        match codes.iter_mut().find(|(seen, _)| *seen == code) {
+            Some((_, count)) => *count += 1,
+            None => codes.push((code, 1)),
+        }
+    }
+    table(&mut out, "Code", codes);
+
+    let file_counts = files
+        .iter()
+        .map(|(file, diagnostics)| (format!("`{}`", file_name(file)), diagnostics.len()))
+        .collect();
+    table(&mut out, "File", file_counts);
+
+    let mut shown = 0;
+    let mut full = false;
+    for (file, diagnostics) in &files {
+        let mut section = format!(
+            "<details>\n<summary><code>{}</code> ({})</summary>\n\n",
+            file_name(file).replace('<', "&lt;"),
+            diagnostics.len()
+        );
+        let header = section.len();
+
+        for diagnostic in diagnostics {
+            // Entries are measured before they are added, a single long one can not push the
+            // comment past the limit
+            let mut entry = String::new();
+            details(&mut entry, diagnostic);
+
+            full = shown >= MAX_DIAGNOSTICS
+                || out.len() + section.len() + entry.len() + SECTION_END.len() > MAX_LENGTH;
+            if full {
+                break;
+            }
+            section.push_str(&entry);
+            shown += 1;
+        }
+
+        if section.len() > header {
+            out.push_str(&section);
+            out.push_str(SECTION_END);
+        }
+        if full {
+            break;
+        }
+    }
+
+    if shown < diagnostics.len() {
+        let _ = writeln!(
+            out,
+            "_{} not shown, run ts-analyzer locally for the full report._",
+            plural(diagnostics.len() - shown, "more diagnostic")
+        );
+    }
+
+    out
+}
+
+/// Summary table of counts, largest first
+fn table(out: &mut String, header: &str, mut rows: Vec<(String, usize)>) {
+    rows.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+
+    let _ = writeln!(out, "| {} | Count |\n| --- | ---: |", header);
+    for (name, count) in rows.iter().take(MAX_ROWS) {
+        let _ = writeln!(out, "| {} | {} |", name.replace('|', "\\|"), count);
+    }
+    if rows.len() > MAX_ROWS {
+        let rest: usize = rows[MAX_ROWS..].iter().map(|(_, count)| count).sum();
+        let _ = writeln!(out, "| _{} others_ | {} |", rows.len() - MAX_ROWS, rest);
+    }
+    out.push('\n');
+}
+
+/// Snippet, message and suggestion of a single diagnostic
+fn details(out: &mut String, diagnostic: &Diagnostic) {
+    let err = &diagnostic.error;
+
+    if err.is_global() {
+        let _ = writeln!(out, "**{}**\n", err.code);
+    } else {
+        let _ = writeln!(
+            out,
+            "**{}** at line {}, column {}\n",
+            err.code, err.line, err.column
+        );
+    }
+
+    if let Some(snippet) = snippet(diagnostic) {
+        out.push_str(&snippet);
+    }
+
+    for line in err.full_message().lines() {
+        let _ = writeln!(out, "> {}", escape(line.trim_start()));
+    }
+    out.push('\n');
+
+    if let Some(suggestion) = &diagnostic.suggestion {
+        for text in suggestion.messages() {
+            let _ = writeln!(out, "- {}", styled(text));
+        }
+        if let Some(help) = &suggestion.help {
+            let _ = writeln!(out, "- **help:** {}", styled(help));
+        }
+        out.push('\n');
+    }
+}
+
+/// Fenced code block with the reported line and its surrounding lines
+fn snippet(diagnostic: &Diagnostic) -> Option<String> {
+    let err = &diagnostic.error;
+    if diagnostic.source.is_empty() || err.line == 0 {
+        return None;
+    }
+
+    let first = err.line.saturating_sub(CONTEXT_LINES).max(1);
+    let lines: Vec<(usize, &str)> = diagnostic
+        .source
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, line))
+        .skip(first - 1)
+        .take(err.line - first + CONTEXT_LINES + 1)
+        .collect();
+    if lines.is_empty() {
+        return None;
+    }
+
+    let width = lines.last()?.0.to_string().len();
+    let code: Vec<String> = lines
+        .iter()
+        .map(|(number, line)| {
+            let marker = if *number == err.line { '>' } else { ' ' };
+            format!("{} {:>width$} | {}", marker, number, line)
+        })
+        .collect();
+
+    // The fence has to be longer than any run of backticks in the code
+    let longest_run = code
+        .iter()
+        .flat_map(|line| line.split(|c| c != '`'))
+        .map(str::len)
+        .max()
+        .unwrap_or(0);
+    let fence = "`".repeat(longest_run.max(2) + 1);
+    let language = if err.file.ends_with(".tsx") {
+        "tsx"
+    } else {
+        "ts"
+    };
+
+    Some(format!(
+        "{fence}{language}\n{}\n{fence}\n\n",
+        code.join("\n")
+    ))
+}
+
+/// Suggestion text with code segments as code spans
+fn styled(text: &StyledText) -> String {
+    text.segments
+        .iter()
+        .map(|segment| match segment.style {
+            Style::Plain => escape(&segment.text),
+            Style::Label => format!("**{}**", escape(&segment.text)),
+            _ => code_span(&segment.text),
+        })
+        .collect()
+}
+
+/// Inline code, delimited by more backticks than the code contains
+fn code_span(code: &str) -> String {
+    let longest_run = code.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest_run + 1);
+
+    if longest_run > 0 {
+        format!("{fence} {code} {fence}")
+    } else {
+        format!("{fence}{code}{fence}")
+    }
+}
+
+/// Escape Markdown and inline HTML
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '<' | '>' | '[' | ']' | '|' | '#'
+        ) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("{} {}", count, noun)
+    } else {
+        format!("{} {}s", count, noun)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        diagnostics::styled::Segment,
+        error::parse,
+        output::test_diagnostics,
+    };
+
+    #[test]
+    fn test_markdown_render() {
+        let err = parse("a.ts(2,7): error TS2304: Cannot find name 'foo'.").unwrap();
+        let source = "const a = 1;\nconst b = foo;\nconst c = 2;\nconst d = 3;\n";
+        let out = render(&[Diagnostic::with_source(err, source.to_string())]);
+
+        assert!(out.contains("**1 diagnostic** in **1 file**"));
+        assert!(out.contains("| TS2304 | 1 |"));
+        assert!(out.contains("<summary><code>a.ts</code> (1)</summary>"));
+        assert!(
+            out.contains(
+                "```ts\n  1 | const a = 1;\n> 2 | const b = foo;\n  3 | const c = 2;\n```"
+            )
+        );
+        assert!(out.contains("> Cannot find name 'foo'."));
+        assert!(out.contains("- Identifier `foo` can not be found"));
+        assert!(!out.contains("const d"));
+    }
+
+    #[test]
+    fn test_markdown_truncates() {
+        let out = render(&test_diagnostics((1..=MAX_DIAGNOSTICS + 5).map(|line| {
+            format!("a.ts({},1): error TS2304: Cannot find name 'x'.", line)
+        })));
+
+        assert!(out.contains(&format!("| TS2304 | {} |", MAX_DIAGNOSTICS + 5)));
+        assert!(out.contains("_5 more diagnostics not shown"));
+    }
+
+    #[test]
+    fn test_markdown_stays_under_length() {
+        let long = "x".repeat(MAX_LENGTH);
+        let out = render(&test_diagnostics([
+            "a.ts(1,1): error TS2304: Cannot find name 'a'.".to_string(),
+            format!("a.ts(2,1): error TS2304: Cannot find name '{}'.", long),
+            "a.ts(3,1): error TS2304: Cannot find name 'c'.".to_string(),
+        ]));
+
+        assert!(out.len() <= MAX_LENGTH);
+        assert!(out.contains("> Cannot find name 'a'."));
+        assert!(out.contains("</details>\n\n_2 more diagnostics not shown"));
+    }
+
+    #[test]
+    fn test_styled_code_spans() {
+        let text = StyledText::format(
+            "Type <T> {} and {}",
+            [Segment::type_name("Array<T>"), Segment::type_name("`${T}`")],
+        );
+
+        assert_eq!(styled(&text), "Type \\<T\\> `Array<T>` and `` `${T}` ``");
+    }
+}
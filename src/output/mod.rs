@@ -0,0 +1,303 @@
+pub mod checkstyle;
+pub mod compact;
+pub mod github;
+pub mod gitlab;
+pub mod html;
+pub mod json;
+pub mod junit;
+pub mod markdown;
+pub mod rdjson;
+pub mod sarif;
+pub mod summary;
+pub mod terminal;
+
+use std::collections::HashMap;
+
+use crate::{
+    diagnostics::{
+        Diagnostic,
+        styled::StyledText,
+    },
+    error::Severity,
+};
+
+/// Output format diagnostics are rendered in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Pretty ariadne reports with suggestions
+    #[default]
+    Terminal,
+    /// Versioned JSON document, see the readme for the schema
+    Json,
+    /// SARIF 2.1.0 log for code scanning dashboards
+    Sarif,
+    /// GitHub Actions workflow commands for inline pull request annotations
+    Github,
+    /// JUnit XML report with a testsuite per file, for test dashboards
+    Junit,
+    /// GitLab Code Quality report
+    Gitlab,
+    /// Checkstyle XML report
+    Checkstyle,
+    /// Reviewdog rdjson with concrete fixes as suggested edits
+    Rdjson,
+    /// Markdown report sized for pull request comments
+    Markdown,
+    /// Self-contained HTML report with highlighted code frames, usually written with --output
+    Html,
+    /// One line per diagnostic for editor quickfix lists and grep
+    Compact,
+}
+
+/// Sections of the terminal report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupBy {
+    /// A section per file
+    File,
+    /// A section per error code
+    Code,
+}
+
+/// Order of the diagnostics of a run, tsc order is kept when none is given
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortBy {
+    /// By file path, then by position in the file
+    Path,
+    /// By position in the file, files stay in the order tsc reported them
+    Line,
+    /// Errors first, then warnings and advice, each in tsc order
+    Severity,
+}
+
+/// Sort diagnostics in place. The sort is stable, so ties keep tsc order.
+pub fn sort(diagnostics: &mut [Diagnostic], sort: SortBy) {
+    match sort {
+        SortBy::Path => diagnostics.sort_by(|a, b| {
+            let (a, b) = (&a.error, &b.error);
+            (&a.file, a.line, a.column).cmp(&(&b.file, b.line, b.column))
+        }),
+        SortBy::Line => {
+            let mut files: Vec<String> = Vec::new();
+            for diagnostic in diagnostics.iter() {
+                if !files.contains(&diagnostic.error.file) {
+                    files.push(diagnostic.error.file.clone());
+                }
+            }
+
+            diagnostics.sort_by_key(|diagnostic| {
+                let err = &diagnostic.error;
+                let file = files.iter().position(|file| *file == err.file);
+                (file, err.line, err.column)
+            });
+        }
+        SortBy::Severity => diagnostics.sort_by_key(|diagnostic| diagnostic.error.severity),
+    }
+}
+
+/// Render all diagnostics of a run in the given format
+pub fn render(format: Format, diagnostics: &[Diagnostic]) -> String {
+    match format {
+        Format::Terminal => terminal::render(diagnostics),
+        Format::Json => json::render(diagnostics),
+        Format::Sarif => sarif::render(diagnostics),
+        Format::Github => github::render(diagnostics),
+        Format::Junit => junit::render(diagnostics),
+        Format::Gitlab => gitlab::render(diagnostics),
+        Format::Checkstyle => checkstyle::render(diagnostics),
+        Format::Rdjson => rdjson::render(diagnostics),
+        Format::Markdown => markdown::render(diagnostics),
+        Format::Html => html::render(diagnostics),
+        Format::Compact => compact::render(diagnostics),
+    }
+}
+
+/// Whether any of the diagnostics is at least as severe as `fail_on`, which fails the run
+pub fn fails(diagnostics: &[Diagnostic], fail_on: Severity) -> bool {
+    diagnostics
+        .iter()
+        .any(|diagnostic| diagnostic.error.severity <= fail_on)
+}
+
+/// Number of diagnostics of the given severity
+pub(crate) fn count(diagnostics: &[Diagnostic], severity: Severity) -> usize {
+    diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.error.severity == severity)
+        .count()
+}
+
+/// Plain text suggestions followed by the help, one per line
+pub(crate) fn suggestion_lines(diagnostic: &Diagnostic) -> Vec<String> {
+    let Some(suggestion) = &diagnostic.suggestion else {
+        return Vec::new();
+    };
+
+    let mut lines: Vec<String> = suggestion.messages().map(StyledText::plain).collect();
+    if let Some(help) = &suggestion.help {
+        lines.push(format!("help: {}", help.plain()));
+    }
+    lines
+}
+
+/// Name shown for diagnostics without a file, like tsconfig errors
+pub(crate) const GLOBAL_FILE: &str = "(global)";
+
+/// File of a diagnostic as shown in reports, [`GLOBAL_FILE`] for global diagnostics
+pub(crate) fn file_name(file: &str) -> &str {
+    if file.is_empty() { GLOBAL_FILE } else { file }
+}
+
+/// Group diagnostics by file, in the order the files are first reported
+pub(crate) fn by_file(diagnostics: &[Diagnostic]) -> Vec<(&str, Vec<&Diagnostic>)> {
+    group(diagnostics, |diagnostic| diagnostic.error.file.as_str())
+}
+
+/// Group items by a key, in the order the keys are first seen
+pub(crate) fn group<'a, T, K: PartialEq>(
+    items: &'a [T], key: impl Fn(&'a T) -> K,
+) -> Vec<(K, Vec<&'a T>)> {
+    let mut groups: Vec<(K, Vec<&T>)> = Vec::new();
+
+    for item in items {
+        let key = key(item);
+        match groups.iter_mut().find(|(group, _)| *group == key) {
+            Some((_, group)) => group.push(item),
+            None => groups.push((key, vec![item])),
+        }
+    }
+
+    groups
+}
+
+/// Diagnostics for lines of tsc output, without reading the files they point at
+#[cfg(test)]
+pub(crate) fn test_diagnostics(
+    lines: impl IntoIterator<Item = impl AsRef<str>>,
+) -> Vec<Diagnostic> {
+    lines
+        .into_iter()
+        .map(|line| {
+            Diagnostic::with_source(crate::error::parse(line.as_ref()).unwrap(), String::new())
+        })
+        .collect()
+}
+
+/// Escape text for XML attributes and character data
+pub(crate) fn xml_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            // Control characters other than tab and newlines are not allowed in XML 1.0
+            c if c.is_control() && !matches!(c, '\t' | '\n' | '\r') => {}
+            c => out.push(c),
+        }
+    }
+
+    out
+}
+
+/// Stable fingerprints for every diagnostic, used to track results across runs.
+///
+/// Fingerprints hash the code, file, message and the text of the reported line rather than line
+/// numbers, so unrelated edits that move code around do not change them. Identical diagnostics
+/// are told apart by their occurrence count.
+pub(crate) fn fingerprints(diagnostics: &[Diagnostic]) -> Vec<String> {
+    let mut occurrences: HashMap<u64, usize> = HashMap::new();
+
+    diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let err = &diagnostic.error;
+            let code = err.code.to_string();
+            let message = err.full_message();
+            let line = diagnostic.source_line().unwrap_or_default().trim();
+
+            let hash = fnv1a(&[&code, &err.file, &message, line]);
+            let occurrence = occurrences.entry(hash).or_default();
+            *occurrence += 1;
+
+            format!("{:016x}:{}", hash, occurrence)
+        })
+        .collect()
+}
+
+/// 64-bit FNV-1a, stable across Rust versions unlike the std hashers
+fn fnv1a(parts: &[&str]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+
+    for part in parts {
+        // Separate parts so ("ab", "c") and ("a", "bc") hash differently
+        for byte in part.bytes().chain(std::iter::once(0)) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fnv1a_is_stable() {
+        assert_eq!(fnv1a(&[]), 0xcbf2_9ce4_8422_2325);
+        assert_ne!(fnv1a(&["ab", "c"]), fnv1a(&["a", "bc"]));
+    }
+
+    #[test]
+    fn test_sort_by_line_keeps_file_order() {
+        let mut diagnostics = test_diagnostics([
+            "b.ts(9,1): error TS2304: Cannot find name 'x'.",
+            "a.ts(5,1): error TS2304: Cannot find name 'x'.",
+            "b.ts(2,1): error TS2304: Cannot find name 'x'.",
+            "a.ts(1,1): error TS2304: Cannot find name 'x'.",
+        ]);
+
+        sort(&mut diagnostics, SortBy::Line);
+        let order: Vec<(&str, usize)> = diagnostics
+            .iter()
+            .map(|diagnostic| (diagnostic.error.file.as_str(), diagnostic.error.line))
+            .collect();
+
+        assert_eq!(
+            order,
+            vec![("b.ts", 2), ("b.ts", 9), ("a.ts", 1), ("a.ts", 5)]
+        );
+    }
+
+    #[test]
+    fn test_reports_end_with_newline() {
+        let diagnostics = test_diagnostics(["a.ts(1,1): error TS2304: Cannot find name 'x'."]);
+
+        for format in [
+            Format::Json,
+            Format::Sarif,
+            Format::Github,
+            Format::Junit,
+            Format::Gitlab,
+            Format::Checkstyle,
+            Format::Rdjson,
+            Format::Markdown,
+            Format::Html,
+            Format::Compact,
+        ] {
+            assert!(render(format, &diagnostics).ends_with('\n'), "{format:?}");
+        }
+    }
+
+    #[test]
+    fn test_xml_escape() {
+        assert_eq!(
+            xml_escape("Type '<T>' & \"x\"\u{1b}"),
+            "Type &apos;&lt;T&gt;&apos; &amp; &quot;x&quot;"
+        );
+    }
+}
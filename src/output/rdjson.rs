@@ -0,0 +1,229 @@
+use serde::Serialize;
+
+use crate::{
+    diagnostics::Diagnostic,
+    error::Severity,
+    output::suggestion_lines,
+};
+
+const SOURCE_URL: &str = "https://github.com/mikkurogue/ts-analyzer";
+
+#[derive(Serialize)]
+struct DiagnosticResult {
+    source:      Source,
+    diagnostics: Vec<RdDiagnostic>,
+}
+
+#[derive(Serialize)]
+struct Source {
+    name: &'static str,
+    url:  &'static str,
+}
+
+#[derive(Serialize)]
+struct RdDiagnostic {
+    message: String,
+    /// Left out for global diagnostics, which have no file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<Location>,
+    severity: &'static str,
+    code: Code,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    suggestions: Vec<RdSuggestion>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    related_locations: Vec<RelatedLocation>,
+}
+
+#[derive(Serialize)]
+struct Location {
+    path:  String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    range: Option<Range>,
+}
+
+#[derive(Serialize)]
+struct Range {
+    start: Position,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end:   Option<Position>,
+}
+
+/// 1-indexed position, the column counts UTF-8 bytes. Positions in files that could not be read
+/// keep the column tsc reports, which counts UTF-16 code units.
+#[derive(Serialize)]
+struct Position {
+    line:   usize,
+    column: usize,
+}
+
+#[derive(Serialize)]
+struct Code {
+    value: String,
+}
+
+#[derive(Serialize)]
+struct RdSuggestion {
+    range: Range,
+    text:  String,
+}
+
+#[derive(Serialize)]
+struct RelatedLocation {
+    message:  String,
+    location: Location,
+}
+
+/// Render diagnostics in reviewdog's rdjson format, with the concrete fixes as suggestions
+pub fn render(diagnostics: &[Diagnostic]) -> String {
+    let result = DiagnosticResult {
+        source:      Source {
+            name: "ts-analyzer",
+            url:  SOURCE_URL,
+        },
+        diagnostics: diagnostics.iter().map(rd_diagnostic).collect(),
+    };
+
+    let mut out = serde_json::to_string_pretty(&result).unwrap_or_default();
+    out.push('\n');
+    out
+}
+
+fn rd_diagnostic(diagnostic: &Diagnostic) -> RdDiagnostic {
+    let err = &diagnostic.error;
+
+    let mut message = err.full_message();
+    for line in suggestion_lines(diagnostic) {
+        message.push('\n');
+        message.push_str(&line);
+    }
+
+    let location = (!err.is_global()).then(|| Location {
+        path:  err.file.clone(),
+        range: Some(if diagnostic.source.is_empty() {
+            tsc_range(err.line, err.column)
+        } else {
+            span_range(&diagnostic.source, &diagnostic.span)
+        }),
+    });
+
+    RdDiagnostic {
+        message,
+        location,
+        severity: severity(err.severity),
+        code: Code {
+            value: err.code.to_string(),
+        },
+        suggestions: diagnostic
+            .fixes
+            .iter()
+            .map(|fix| RdSuggestion {
+                range: span_range(&diagnostic.source, &fix.span),
+                text:  fix.replacement.clone(),
+            })
+            .collect(),
+        related_locations: err
+            .related
+            .iter()
+            .map(|info| {
+                // Related locations in files that were read are resolved to a span
+                let resolved = diagnostic.related.iter().find(|r| r.info == *info);
+                let range = match (resolved, diagnostic.source_of(&info.file)) {
+                    (Some(related), Some(source)) => span_range(source, &related.span),
+                    _ => tsc_range(info.line, info.column),
+                };
+
+                RelatedLocation {
+                    message:  info.message.clone(),
+                    location: Location {
+                        path:  info.file.clone(),
+                        range: Some(range),
+                    },
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Range starting at the position tsc reports, for files that could not be read
+fn tsc_range(line: usize, column: usize) -> Range {
+    Range {
+        start: Position { line, column },
+        end:   None,
+    }
+}
+
+/// Range of a byte span in the source
+fn span_range(source: &str, span: &std::ops::Range<usize>) -> Range {
+    Range {
+        start: position(source, span.start),
+        end:   Some(position(source, span.end)),
+    }
+}
+
+/// Position of a byte offset in the source
+fn position(source: &str, offset: usize) -> Position {
+    let before = &source.as_bytes()[..offset.min(source.len())];
+    let line_start = before
+        .iter()
+        .rposition(|byte| *byte == b'\n')
+        .map(|idx| idx + 1)
+        .unwrap_or(0);
+
+    Position {
+        line:   before.iter().filter(|byte| **byte == b'\n').count() + 1,
+        column: before.len() - line_start + 1,
+    }
+}
+
+fn severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "ERROR",
+        Severity::Warning => "WARNING",
+        Severity::Advice => "INFO",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::parse;
+
+    #[test]
+    fn test_rdjson_render() {
+        let mut err = parse(
+            "a.ts(2,15): error TS2551: Property 'lenght' does not exist on type 'string'. Did you \
+             mean 'length'?",
+        )
+        .unwrap();
+        err.elaborate("  a.ts(1,16): 't' is declared here.");
+        let global = parse("error TS18003: No inputs were found in config file.").unwrap();
+        // tsc counts the `é` before each location as one column, UTF-8 as two
+        let source = "const s = 'é', t = s;\nconst n = 'é'.lenght;\n";
+        let diagnostics = [
+            Diagnostic::with_source(err, source.to_string()),
+            Diagnostic::with_source(global, String::new()),
+        ];
+        let result: serde_json::Value = serde_json::from_str(&render(&diagnostics)).unwrap();
+        let diagnostic = &result["diagnostics"][0];
+
+        assert_eq!(diagnostic["code"]["value"], "TS2551");
+        assert_eq!(diagnostic["severity"], "ERROR");
+        assert_eq!(
+            diagnostic["location"]["range"],
+            serde_json::json!({
+                "start": { "line": 2, "column": 16 },
+                "end": { "line": 2, "column": 22 },
+            })
+        );
+        assert_eq!(diagnostic["suggestions"][0]["text"], "length");
+        assert_eq!(
+            diagnostic["suggestions"][0]["range"]["start"],
+            serde_json::json!({ "line": 2, "column": 16 })
+        );
+        assert_eq!(
+            diagnostic["related_locations"][0]["location"]["range"]["start"],
+            serde_json::json!({ "line": 1, "column": 17 })
+        );
+        assert!(result["diagnostics"][1].get("location").is_none());
+    }
+}
@@ -0,0 +1,317 @@
+use serde::Serialize;
+
+use crate::{
+    diagnostics::Diagnostic,
+    error::{
+        Severity,
+        codes::ErrorCode,
+    },
+    output::{
+        fingerprints,
+        suggestion_lines,
+    },
+};
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const INFORMATION_URI: &str = "https://github.com/mikkurogue/ts-analyzer";
+/// Key of the fingerprint in `partialFingerprints`, versioned in case the hash inputs change
+const FINGERPRINT_KEY: &str = "tsAnalyzer/v1";
+
+#[derive(Serialize)]
+struct Log {
+    #[serde(rename = "$schema")]
+    schema:  &'static str,
+    version: &'static str,
+    runs:    Vec<Run>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Run {
+    tool:        Tool,
+    column_kind: &'static str,
+    results:     Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct Tool {
+    driver: Driver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<Rule>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Rule {
+    id: String,
+    name: String,
+    short_description: Message,
+    help: Message,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: String,
+    rule_index: usize,
+    level: &'static str,
+    message: Message,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    related_locations: Vec<Location>,
+    partial_fingerprints: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message:           Option<Message>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactLocation {
+    uri:         String,
+    uri_base_id: &'static str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line:   usize,
+    start_column: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_line:     Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_column:   Option<usize>,
+}
+
+/// Render diagnostics as a SARIF 2.1.0 log with one rule per error code
+pub fn render(diagnostics: &[Diagnostic]) -> String {
+    let mut rules: Vec<Rule> = Vec::new();
+    let fingerprints = fingerprints(diagnostics);
+
+    let results = diagnostics
+        .iter()
+        .zip(fingerprints)
+        .map(|(diagnostic, fingerprint)| {
+            let err = &diagnostic.error;
+            let rule_id = err.code.to_string();
+            let rule_index = match rules.iter().position(|rule| rule.id == rule_id) {
+                Some(index) => index,
+                None => {
+                    rules.push(rule(err.code));
+                    rules.len() - 1
+                }
+            };
+
+            let mut text = err.full_message();
+            for line in suggestion_lines(diagnostic) {
+                text.push('\n');
+                text.push_str(&line);
+            }
+
+            let locations = if err.is_global() {
+                Vec::new()
+            } else {
+                let end = diagnostic.end_position();
+                vec![location(
+                    &err.file,
+                    Region {
+                        start_line:   err.line,
+                        start_column: err.column,
+                        end_line:     end.map(|(line, _)| line),
+                        end_column:   end.map(|(_, column)| column),
+                    },
+                    None,
+                )]
+            };
+
+            let related_locations = err
+                .related
+                .iter()
+                .map(|info| {
+                    location(
+                        &info.file,
+                        Region {
+                            start_line:   info.line,
+                            start_column: info.column,
+                            end_line:     None,
+                            end_column:   None,
+                        },
+                        Some(info.message.clone()),
+                    )
+                })
+                .collect();
+
+            let mut partial_fingerprints = serde_json::Map::new();
+            partial_fingerprints.insert(FINGERPRINT_KEY.to_string(), fingerprint.into());
+
+            SarifResult {
+                rule_id,
+                rule_index,
+                level: level(err.severity),
+                message: Message { text },
+                locations,
+                related_locations,
+                partial_fingerprints,
+            }
+        })
+        .collect();
+
+    let log = Log {
+        schema:  SCHEMA,
+        version: "2.1.0",
+        runs:    vec![Run {
+            tool: Tool {
+                driver: Driver {
+                    name: "ts-analyzer",
+                    version: env!("CARGO_PKG_VERSION"),
+                    information_uri: INFORMATION_URI,
+                    rules,
+                },
+            },
+            // tsc counts columns in UTF-16 code units
+            column_kind: "utf16CodeUnits",
+            results,
+        }],
+    };
+
+    let mut out = serde_json::to_string_pretty(&log).unwrap_or_default();
+    out.push('\n');
+    out
+}
+
+/// Describe an error code as a rule with the help for the code, the help specific to each
+/// diagnostic is part of the result message
+fn rule(code: ErrorCode) -> Rule {
+    // The variant name, without the code number some variants carry
+    let name = format!("{:?}", code);
+    let name = name.split('(').next().unwrap_or_default().to_string();
+
+    Rule {
+        id: code.to_string(),
+        short_description: Message {
+            text: format!("TypeScript error {}", code),
+        },
+        help: Message {
+            text: code.help().to_string(),
+        },
+        name,
+    }
+}
+
+fn location(file: &str, region: Region, message: Option<String>) -> Location {
+    Location {
+        physical_location: PhysicalLocation {
+            artifact_location: ArtifactLocation {
+                uri:         uri(file),
+                uri_base_id: "%SRCROOT%",
+            },
+            region,
+        },
+        message:           message.map(|text| Message { text }),
+    }
+}
+
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Advice => "note",
+    }
+}
+
+/// Relative URI reference for a path as printed by tsc
+fn uri(path: &str) -> String {
+    let mut out = String::with_capacity(path.len());
+
+    for c in path.chars() {
+        match c {
+            '\\' => out.push('/'),
+            ' ' => out.push_str("%20"),
+            '%' => out.push_str("%25"),
+            '#' => out.push_str("%23"),
+            '?' => out.push_str("%3F"),
+            '[' => out.push_str("%5B"),
+            ']' => out.push_str("%5D"),
+            c => out.push(c),
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::parse,
+        output::test_diagnostics,
+    };
+
+    #[test]
+    fn test_sarif_rules_and_fingerprints() {
+        let diagnostics = test_diagnostics([
+            "src/a b.ts(1,7): error TS2304: Cannot find name 'foo'.",
+            "src/a b.ts(1,7): error TS2304: Cannot find name 'foo'.",
+            "src/c.ts(2,1): error TS2304: Cannot find name 'bar'.",
+        ]);
+
+        let log: serde_json::Value = serde_json::from_str(&render(&diagnostics)).unwrap();
+        let run = &log["runs"][0];
+
+        assert_eq!(log["version"], "2.1.0");
+        assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), 1);
+        assert_eq!(run["results"][2]["ruleIndex"], 0);
+        assert_eq!(
+            run["results"][0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "src/a%20b.ts"
+        );
+
+        let fingerprint =
+            |i: usize| run["results"][i]["partialFingerprints"][FINGERPRINT_KEY].clone();
+        assert_ne!(fingerprint(0), fingerprint(1));
+        assert_ne!(fingerprint(0), fingerprint(2));
+        assert_eq!(
+            run["tool"]["driver"]["rules"][0]["help"]["text"],
+            "Declare the name, or import it."
+        );
+    }
+
+    #[test]
+    fn test_sarif_columns_count_utf16_code_units() {
+        let err = parse("a.ts(1,15): error TS2304: Cannot find name 'foo'.").unwrap();
+        let source = "let s = \"\u{1F600}\"; foo;\n".to_string();
+        let log: serde_json::Value =
+            serde_json::from_str(&render(&[Diagnostic::with_source(err, source)])).unwrap();
+        let run = &log["runs"][0];
+        let region = &run["results"][0]["locations"][0]["physicalLocation"]["region"];
+
+        assert_eq!(run["columnKind"], "utf16CodeUnits");
+        assert_eq!(region["startColumn"], 15);
+        assert_eq!(region["endColumn"], 18);
+    }
+}
@@ -0,0 +1,129 @@
+use std::{
+    fmt::Write,
+    path::Path,
+};
+
+use colored::*;
+
+use crate::{
+    diagnostics::Diagnostic,
+    output::{
+        GLOBAL_FILE,
+        file_name,
+        group,
+    },
+};
+
+/// Most rows of each table, the rest are added up in a single row
+const MAX_ROWS: usize = 10;
+
+/// Counts of a run, shown at the end of the terminal report
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Summary {
+    /// Diagnostics per error code, most frequent first
+    pub codes: Vec<(String, usize)>,
+    /// Diagnostics per file, most frequent first
+    pub files: Vec<(String, usize)>,
+    /// Diagnostics per directory, most frequent first
+    pub directories: Vec<(String, usize)>,
+    /// Diagnostics ts-analyzer had neither a suggestion nor help for
+    pub without_suggestion: usize,
+}
+
+impl Summary {
+    pub fn new(diagnostics: &[Diagnostic]) -> Self {
+        Self {
+            codes: counts(diagnostics, |d| d.error.code.to_string()),
+            files: counts(diagnostics, |d| file_name(&d.error.file).to_string()),
+            directories: counts(diagnostics, |d| directory(&d.error.file)),
+            without_suggestion: diagnostics.iter().filter(|d| !has_suggestion(d)).count(),
+        }
+    }
+
+    /// Terminal tables of the counts
+    pub fn render(&self) -> String {
+        let mut out = format!("\n{}\n", "Summary".bold());
+
+        table(&mut out, "By code", &self.codes);
+        table(&mut out, "By file", &self.files);
+        table(&mut out, "By directory", &self.directories);
+
+        let _ = writeln!(
+            out,
+            "\n  Without suggestion: {}",
+            self.without_suggestion.to_string().bold()
+        );
+        out
+    }
+}
+
+/// Number of diagnostics per key, most frequent first and in first-seen order on ties
+fn counts(diagnostics: &[Diagnostic], key: impl Fn(&Diagnostic) -> String) -> Vec<(String, usize)> {
+    let mut counts: Vec<(String, usize)> = group(diagnostics, key)
+        .into_iter()
+        .map(|(key, group)| (key, group.len()))
+        .collect();
+
+    counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+    counts
+}
+
+fn table(out: &mut String, title: &str, rows: &[(String, usize)]) {
+    let shown = &rows[..rows.len().min(MAX_ROWS)];
+    let width = shown.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+
+    let _ = writeln!(out, "\n  {}", title.bold());
+    for (name, count) in shown {
+        let _ = writeln!(out, "    {:<width$}  {}", name, count.to_string().bold());
+    }
+    if rows.len() > MAX_ROWS {
+        let rest: usize = rows[MAX_ROWS..].iter().map(|(_, count)| count).sum();
+        let others = format!("{:<width$}", format!("{} others", rows.len() - MAX_ROWS));
+        let _ = writeln!(out, "    {}  {}", others.italic(), rest.to_string().bold());
+    }
+}
+
+fn has_suggestion(diagnostic: &Diagnostic) -> bool {
+    diagnostic
+        .suggestion
+        .as_ref()
+        .is_some_and(|s| s.label.is_some() || s.help.is_some())
+}
+
+/// Directory of a file, `.` for files in the current directory
+fn directory(file: &str) -> String {
+    if file.is_empty() {
+        return GLOBAL_FILE.to_string();
+    }
+
+    match Path::new(file).parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.display().to_string(),
+        _ => ".".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::test_diagnostics;
+
+    #[test]
+    fn test_summary_counts() {
+        let summary = Summary::new(&test_diagnostics([
+            "src/a.ts(1,1): error TS2304: Cannot find name 'x'.",
+            "src/b.ts(1,1): error TS2304: Cannot find name 'y'.",
+            "src/b.ts(2,1): error TS2322: Type 'string' is not assignable to type 'number'.",
+            "main.ts(1,1): error TS2304: Cannot find name 'z'.",
+        ]));
+
+        assert_eq!(
+            summary.codes,
+            vec![("TS2304".to_string(), 3), ("TS2322".to_string(), 1)]
+        );
+        assert_eq!(summary.files[0], ("src/b.ts".to_string(), 2));
+        assert_eq!(
+            summary.directories,
+            vec![("src".to_string(), 3), (".".to_string(), 1)]
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use std::fmt::Write;
+
+use colored::*;
+
+use crate::{
+    diagnostics::Diagnostic,
+    error::Severity,
+    formatter::{
+        self,
+        RenderOptions,
+    },
+    output::{
+        GLOBAL_FILE,
+        GroupBy,
+        count,
+        file_name,
+        group,
+        summary::Summary,
+    },
+};
+
+/// Layout of the terminal report
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    /// Sections per file or error code, diagnostics are listed in run order without one
+    pub group_by: Option<GroupBy>,
+    /// Render only the first of the diagnostics with the same code and message and list where
+    /// the others are
+    pub collapse: bool,
+    /// How the code frame of every diagnostic is drawn
+    pub render:   RenderOptions,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            group_by: None,
+            collapse: true,
+            render:   RenderOptions::default(),
+        }
+    }
+}
+
+/// Render every diagnostic as an ariadne report followed by the summary and error count
+pub fn render(diagnostics: &[Diagnostic]) -> String {
+    render_with(diagnostics, &Options::default())
+}
+
+/// Render the terminal report with the given layout
+pub fn render_with(diagnostics: &[Diagnostic], options: &Options) -> String {
+    let mut out = String::new();
+
+    match options.group_by {
+        None => out.push_str(&reports(diagnostics, options)),
+        Some(group_by) => {
+            let groups = group(diagnostics, |diagnostic| match group_by {
+                GroupBy::File => file_name(&diagnostic.error.file).to_string(),
+                GroupBy::Code => diagnostic.error.code.to_string(),
+            });
+
+            let title = match group_by {
+                GroupBy::File => "File",
+                GroupBy::Code => "Code",
+            };
+            for (name, diagnostics) in groups {
+                out.push_str(&format!(
+                    "\n{} {} ({})\n\n",
+                    title.bold(),
+                    name.cyan().bold(),
+                    diagnostics.len()
+                ));
+                out.push_str(&reports(diagnostics, options));
+            }
+        }
+    }
+
+    if diagnostics.is_empty() {
+        out.push_str("No errors were emitted.\n");
+    } else {
+        out.push_str(&Summary::new(diagnostics).render());
+    }
+
+    out.push_str(&format!("\n{}\n", totals(diagnostics)));
+    out
+}
+
+/// Line with the number of errors, followed by the warnings and advice when there are any
+pub fn totals(diagnostics: &[Diagnostic]) -> String {
+    let mut out = format!(
+        "Total errors: {}",
+        count(diagnostics, Severity::Error).to_string().red().bold()
+    );
+
+    let warnings = count(diagnostics, Severity::Warning);
+    if warnings > 0 {
+        out.push_str(&format!(
+            ", warnings: {}",
+            warnings.to_string().yellow().bold()
+        ));
+    }
+    let advice = count(diagnostics, Severity::Advice);
+    if advice > 0 {
+        out.push_str(&format!(", advice: {}", advice.to_string().blue().bold()));
+    }
+    out
+}
+
+/// Ariadne reports of the diagnostics, repeated diagnostics are collapsed unless disabled
+pub fn reports<'a>(
+    diagnostics: impl IntoIterator<Item = &'a Diagnostic>, options: &Options,
+) -> String {
+    let diagnostics: Vec<&Diagnostic> = diagnostics.into_iter().collect();
+    let mut out = String::new();
+
+    if !options.collapse {
+        for diagnostic in diagnostics {
+            out.push_str(&formatter::render_with(diagnostic, &options.render));
+            out.push('\n');
+        }
+        return out;
+    }
+
+    let clusters = group(&diagnostics, |diagnostic| {
+        (
+            diagnostic.error.code.to_string(),
+            normalize(&diagnostic.error.full_message()),
+        )
+    });
+    for (_, cluster) in clusters {
+        out.push_str(&formatter::render_with(cluster[0], &options.render));
+        if cluster.len() > 1 {
+            out.push_str(&more_locations(&cluster[1..]));
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+/// The message with runs of whitespace collapsed, so reflowed messages still match
+fn normalize(message: &str) -> String {
+    message.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// `and N more at` followed by the locations of the collapsed diagnostics, a line per file
+fn more_locations(diagnostics: &[&&Diagnostic]) -> String {
+    let mut out = format!(
+        "{} {} more at:\n",
+        "and".bright_black(),
+        diagnostics.len().to_string().bold()
+    );
+
+    let files = group(diagnostics, |diagnostic| diagnostic.error.file.as_str());
+    for (file, diagnostics) in files {
+        if file.is_empty() {
+            let _ = writeln!(out, "    {} {}", GLOBAL_FILE, diagnostics.len());
+            continue;
+        }
+
+        let positions: Vec<String> = diagnostics
+            .iter()
+            .map(|diagnostic| format!("{}:{}", diagnostic.error.line, diagnostic.error.column))
+            .collect();
+        let _ = writeln!(
+            out,
+            "    {} {}",
+            file.cyan(),
+            positions.join(", ").bright_black()
+        );
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::test_diagnostics;
+
+    #[test]
+    fn test_collapse_repeated_diagnostics() {
+        // Reports are colored when the tests run in a terminal
+        colored::control::set_override(false);
+
+        let diagnostics = test_diagnostics([
+            "a.ts(1,7): error TS2322: Type 'string' is not assignable to type 'number'.",
+            "a.ts(4,7): error TS2322: Type 'string' is not assignable to type 'number'.",
+            "b.ts(2,3): error TS2322: Type 'string' is not assignable to type 'number'.",
+            "b.ts(5,1): error TS2304: Cannot find name 'x'.",
+        ]);
+
+        let out = reports(&diagnostics, &Options::default());
+
+        assert_eq!(out.matches("error TS2322").count(), 1);
+        assert!(out.contains("and 2 more at:\n    a.ts 4:7\n    b.ts 2:3\n"));
+        assert!(out.contains("error TS2304"));
+    }
+}
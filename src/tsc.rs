@@ -0,0 +1,233 @@
+use std::{
+    path::{
+        Path,
+        PathBuf,
+    },
+    process::Command,
+};
+
+use anyhow::{
+    Context,
+    Result,
+};
+
+/// Flags passed to every tsc invocation so its output can be parsed
+const OUTPUT_FLAGS: [&str; 7] = [
+    "--pretty",
+    "false",
+    "--diagnostics",
+    "--extendedDiagnostics",
+    "--noEmit",
+    "--preserveWatchOutput",
+    "false",
+];
+
+/// Compiler options used to check a single file that no tsconfig.json applies to
+const STANDALONE_FLAGS: [&str; 3] = ["--noUnusedLocals", "--noUnusedParameters", "--strict"];
+
+/// How to locate and invoke the TypeScript compiler
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    /// Path to the tsc executable. Defaults to the nearest `node_modules/.bin/tsc`, then `tsc`
+    /// from PATH
+    pub compiler: Option<PathBuf>,
+    /// tsconfig.json to check, forwarded to tsc as `--project`
+    pub project:  Option<PathBuf>,
+    /// Extra arguments forwarded to tsc verbatim
+    pub args:     Vec<String>,
+}
+
+/// Run `tsc` and capture its combined stdout and stderr.
+///
+/// When `file` is given only that file is checked, otherwise the whole project. A single file is
+/// checked with the options of the project config (or the nearest tsconfig.json) so the results
+/// match a full project check.
+pub fn run(file: Option<&str>, options: &Options) -> Result<String> {
+    let (mut command, compiler) = command(options)?;
+    // The temporary config has to outlive the tsc process, it is removed when dropped
+    let mut _config = None;
+
+    if let Some(input_file) = file {
+        let path = std::path::absolute(input_file)?;
+        let tsconfig = match &options.project {
+            Some(project) => Some(std::path::absolute(project)?),
+            None => path.parent().and_then(find_tsconfig),
+        };
+
+        if let Some(tsconfig) = tsconfig {
+            let config = SingleFileConfig::create(&tsconfig, &path)?;
+            command.arg("--project").arg(&config.path);
+            _config = Some(config);
+        } else {
+            // Without a tsconfig.json there is nothing to derive options from, so fall back to
+            // a strict set of defaults
+            command.arg(input_file).args(STANDALONE_FLAGS);
+        }
+    } else if let Some(project) = &options.project {
+        command.arg("--project").arg(project);
+    }
+
+    command.args(OUTPUT_FLAGS).args(&options.args);
+    capture(command, &compiler)
+}
+
+/// Run `tsc --build` for the project (and its references) and capture its output.
+///
+/// The build runs with `--verbose` so [`crate::build::parse_output`] can attribute every
+/// diagnostic to the project it came from. Unlike [`run`] this emits, as `--noEmit` is not
+/// supported in build mode.
+pub fn build(options: &Options) -> Result<String> {
+    let (mut command, compiler) = command(options)?;
+
+    command.arg("--build");
+    if let Some(project) = &options.project {
+        command.arg(project);
+    }
+
+    command
+        .args(["--verbose", "--pretty", "false"])
+        .args(&options.args);
+    capture(command, &compiler)
+}
+
+/// Create the command for the configured or resolved compiler
+fn command(options: &Options) -> Result<(Command, PathBuf)> {
+    let compiler = match &options.compiler {
+        Some(compiler) => compiler.clone(),
+        None => {
+            let cwd = std::env::current_dir()?;
+            let start = options
+                .project
+                .as_deref()
+                .and_then(Path::parent)
+                .map(|dir| cwd.join(dir))
+                .unwrap_or(cwd);
+            resolve_compiler(&start)
+        }
+    };
+
+    Ok((Command::new(&compiler), compiler))
+}
+
+/// Run the command and combine its stdout and stderr
+fn capture(mut command: Command, compiler: &Path) -> Result<String> {
+    let output = command.output().with_context(|| {
+        format!(
+            "failed to run {}, is TypeScript installed?",
+            compiler.display()
+        )
+    })?;
+
+    Ok(format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    ))
+}
+
+/// Locate the project-local compiler, walking up from `dir` so packages in a monorepo find the
+/// compiler installed at the workspace root. Falls back to `tsc` from PATH.
+pub fn resolve_compiler(dir: &Path) -> PathBuf {
+    let bin = if cfg!(windows) { "tsc.cmd" } else { "tsc" };
+
+    dir.ancestors()
+        .map(|ancestor| ancestor.join("node_modules").join(".bin").join(bin))
+        .find(|candidate| candidate.is_file())
+        .unwrap_or_else(|| PathBuf::from(bin))
+}
+
+/// Find the nearest `tsconfig.json`, walking up from `dir`
+pub fn find_tsconfig(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|ancestor| ancestor.join("tsconfig.json"))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Temporary tsconfig that extends a project config and restricts it to a single file.
+///
+/// It is written next to the config it extends so `typeRoots`, `node_modules` and relative
+/// options resolve exactly as they do for the project, and removed again when dropped.
+struct SingleFileConfig {
+    path: PathBuf,
+}
+
+impl SingleFileConfig {
+    fn create(tsconfig: &Path, file: &Path) -> Result<Self> {
+        let dir = tsconfig.parent().unwrap_or_else(|| Path::new("."));
+        let path = dir.join(format!("tsconfig.ts-analyzer-{}.json", std::process::id()));
+        let contents = single_file_config(tsconfig, file);
+
+        std::fs::write(&path, serde_json::to_string_pretty(&contents)?)
+            .with_context(|| format!("failed to write temporary config {}", path.display()))?;
+
+        Ok(Self { path })
+    }
+}
+
+impl Drop for SingleFileConfig {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Build a config extending `tsconfig` that only checks `file`
+fn single_file_config(tsconfig: &Path, file: &Path) -> serde_json::Value {
+    let extends = tsconfig
+        .file_name()
+        .map(|name| format!("./{}", name.to_string_lossy()))
+        .unwrap_or_else(|| tsconfig.to_string_lossy().into_owned());
+
+    serde_json::json!({
+        "extends": extends,
+        // Composite projects require every imported file to be listed in `files`
+        "compilerOptions": { "composite": false },
+        "files": [file.to_string_lossy()],
+        // `include` is inherited from the extended config unless overridden
+        "include": [],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_file_config() {
+        let config = single_file_config(
+            Path::new("/repo/tsconfig.json"),
+            Path::new("/repo/src/index.ts"),
+        );
+
+        assert_eq!(config["extends"], "./tsconfig.json");
+        assert_eq!(config["files"], serde_json::json!(["/repo/src/index.ts"]));
+        assert_eq!(config["include"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_resolve_compiler_walks_up() {
+        let root = std::env::temp_dir().join(format!("ts-analyzer-tsc-{}", std::process::id()));
+        let bin = root.join("node_modules").join(".bin");
+        let package = root.join("packages").join("app");
+        std::fs::create_dir_all(&bin).unwrap();
+        std::fs::create_dir_all(&package).unwrap();
+
+        let compiler = bin.join(if cfg!(windows) { "tsc.cmd" } else { "tsc" });
+        std::fs::write(&compiler, "").unwrap();
+
+        assert_eq!(resolve_compiler(&package), compiler);
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_find_tsconfig_walks_up() {
+        let root = std::env::temp_dir().join(format!("ts-analyzer-find-{}", std::process::id()));
+        let nested = root.join("src").join("components");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(root.join("tsconfig.json"), "{}").unwrap();
+
+        assert_eq!(find_tsconfig(&nested), Some(root.join("tsconfig.json")));
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}